@@ -0,0 +1,1030 @@
+use crate::boss::FieldTarget;
+use crate::config::config::*;
+use crate::deck::{Card, CardPiles, CardType, Deck, Hand};
+use crate::effects::{StatusEffect, StatusTrigger};
+use crate::enemy::Enemy;
+use crate::enemy_ai::{AiContext, EnemyAi};
+use crate::entity::Entity;
+use crate::gameturn::GameTurn;
+use crate::modifiers::Stat;
+use crate::player::Player;
+use crate::replay::BattleReplay;
+use crate::status_registry::StatusRegistry;
+use ::rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Quem realiza ou sofre uma ação; os inimigos são identificados pela posição no encontro
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combatant {
+    Player,
+    Enemy(usize),
+}
+
+/// Ações que a camada de interface envia ao motor de batalha
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BattleAction {
+    /// Jogador usa a carta no índice indicado da mão
+    PlayCard(usize),
+    /// O próximo inimigo da fila executa sua jogada
+    EnemyAct,
+    /// Encerra o turno atual e passa a vez
+    EndTurn,
+    /// Escolhe o inimigo que recebe as cartas de alvo único do jogador
+    SelectTarget(usize),
+}
+
+/// Resultado final de uma batalha
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BattleOutcome {
+    PlayerWon,
+    EnemyWon,
+    Draw,
+}
+
+/// Eventos produzidos pelo motor ao resolver uma ação, na ordem em que aconteceram
+#[derive(Debug, Clone)]
+pub enum BattleEvent {
+    CardPlayed { actor: Combatant, card: Card },
+    AttackUp { actor: Combatant, amount: u32 },
+    DefenseUp { actor: Combatant, amount: u32 },
+    BuffExpired { target: Combatant, stat: Stat, amount: u32 },
+    Healed { target: Combatant, amount: u32 },
+    StatusApplied { target: Combatant, effect: StatusEffect, duration: u32 },
+    /// Golpe no alvo; `damage` é o valor bruto, `actual` o que chegou à vida e `blocked` o que o escudo absorveu
+    Damage { attacker: Combatant, target: Combatant, damage: u32, actual: u32, blocked: u32 },
+    ShieldUp { actor: Combatant, amount: u32 },
+    ShieldExpired { target: Combatant, amount: u32 },
+    /// Disparo de um efeito de status; espinhos também chegam por aqui, no atacante
+    StatusTick { target: Combatant, effect: StatusEffect, damage: u32, healed: u32 },
+    StatusExpired { target: Combatant, effect: StatusEffect },
+    Stunned(Combatant),
+    NoCards(Combatant),
+    /// O jogador ainda tem cartas, mas nenhuma cabe na energia que sobrou
+    OutOfEnergy,
+    /// O descarte voltou para a pilha de compra
+    Reshuffled { cards: usize },
+    CardExhausted(Card),
+    TurnStarted(Combatant),
+    /// O chefe entrou na fase de número `phase` (a partir de 1)
+    PhaseChanged { enemy: usize, phase: usize, name: String },
+    /// Um chefe chamou reforços, que entram na vaga de um inimigo caído ou no fim da fila
+    Summoned { summoner: usize, enemy: usize },
+    TargetSelected(usize),
+    EnemyDefeated(usize),
+    BattleEnded(BattleOutcome),
+}
+
+/// Próxima jogada anunciada pelo inimigo, mostrada ao jogador durante o turno dele
+#[derive(Debug, Clone)]
+pub struct EnemyIntent {
+    pub card: Card,
+    /// Dano previsto por golpe, já com os efeitos de status atuais; zero se a carta não ataca
+    pub damage: u32,
+    pub hits: u32,
+}
+
+/// Regras de combate sem nenhuma dependência de janela, áudio ou entrada.
+/// Toda a aleatoriedade da batalha vem de `rng`, então a mesma semente e as
+/// mesmas ações reproduzem exatamente a mesma luta.
+pub struct BattleEngine {
+    pub player: Player,
+    pub enemies: Vec<Enemy>,
+    pub piles: CardPiles,
+    pub turn: GameTurn,
+    statuses: StatusRegistry,
+    enemy_ais: Vec<Box<dyn EnemyAi>>,
+    /// Inimigo que recebe as cartas de alvo único do jogador
+    target: usize,
+    /// Posição do próximo inimigo a agir no turno dos inimigos
+    next_enemy: usize,
+    /// Inimigos cuja derrota já foi anunciada
+    fallen: Vec<bool>,
+    seed: u64,
+    rng: ChaCha12Rng,
+    outcome: Option<BattleOutcome>,
+    replay: BattleReplay,
+}
+
+/// Estado completo do motor no save. As IAs entram pelo progresso de cada uma, já que o tipo
+/// delas está no inimigo; a gravação vai junto só como histórico da luta.
+#[derive(Serialize, Deserialize)]
+struct SavedBattle {
+    player: Player,
+    enemies: Vec<Enemy>,
+    piles: CardPiles,
+    turn: GameTurn,
+    statuses: StatusRegistry,
+    ai_progress: Vec<usize>,
+    target: usize,
+    next_enemy: usize,
+    fallen: Vec<bool>,
+    seed: u64,
+    rng: ChaCha12Rng,
+    outcome: Option<BattleOutcome>,
+    replay: BattleReplay,
+}
+
+impl Serialize for BattleEngine {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedBattle {
+            player: self.player.clone(),
+            enemies: self.enemies.clone(),
+            piles: self.piles.clone(),
+            turn: self.turn.clone(),
+            statuses: self.statuses.clone(),
+            ai_progress: self.enemy_ais.iter().map(|ai| ai.progress()).collect(),
+            target: self.target,
+            next_enemy: self.next_enemy,
+            fallen: self.fallen.clone(),
+            seed: self.seed,
+            rng: self.rng.clone(),
+            outcome: self.outcome,
+            replay: self.replay.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BattleEngine {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let saved = SavedBattle::deserialize(deserializer)?;
+        if saved.ai_progress.len() != saved.enemies.len() || saved.fallen.len() != saved.enemies.len() {
+            return Err(serde::de::Error::custom("the saved battle has mismatched enemy lists"));
+        }
+        let enemy_ais = saved.enemies.iter().zip(&saved.ai_progress).map(|(enemy, progress)| enemy.ai.resume(*progress)).collect();
+        Ok(Self {
+            player: saved.player,
+            enemies: saved.enemies,
+            piles: saved.piles,
+            turn: saved.turn,
+            statuses: saved.statuses,
+            enemy_ais,
+            target: saved.target,
+            next_enemy: saved.next_enemy,
+            fallen: saved.fallen,
+            seed: saved.seed,
+            rng: saved.rng,
+            outcome: saved.outcome,
+            replay: saved.replay,
+        })
+    }
+}
+
+impl BattleEngine {
+    /// Embaralha o baralho e distribui as mãos iniciais a partir da semente
+    pub fn new(mut player: Player, mut enemies: Vec<Enemy>, mut deck: Deck, statuses: StatusRegistry, max_turns: u32, seed: u64) -> Self {
+        let replay = BattleReplay {
+            seed,
+            max_turns,
+            player: player.clone(),
+            enemies: enemies.clone(),
+            deck: deck.clone(),
+            statuses: statuses.clone(),
+            actions: Vec::new(),
+        };
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+
+        deck.shuffle(&mut rng);
+        player.hand = Hand::new_from_deck(&mut deck, INITIAL_HAND_SIZE);
+        let piles = CardPiles::new(deck);
+        for enemy in &mut enemies {
+            enemy.hand = Hand::new();
+            enemy.hand.draw_with_chances(&enemy.card_pool, INITIAL_HAND_SIZE, &mut rng);
+        }
+
+        let enemy_ais = enemies.iter().map(|enemy| enemy.ai.build()).collect();
+        let fallen = vec![false; enemies.len()];
+        let mut engine = Self {
+            player,
+            enemies,
+            piles,
+            turn: GameTurn::new(max_turns, PLAYER_MAX_ENERGY),
+            statuses,
+            enemy_ais,
+            target: 0,
+            next_enemy: 0,
+            fallen,
+            seed,
+            rng,
+            outcome: None,
+            replay,
+        };
+        engine.plan_enemy_turns();
+        engine
+    }
+
+    /// Sorteia uma semente nova para batalhas sem semente definida
+    pub fn random_seed() -> u64 {
+        ::rand::random()
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn statuses(&self) -> &StatusRegistry {
+        &self.statuses
+    }
+
+    pub fn entity(&self, combatant: Combatant) -> &dyn Entity {
+        match combatant {
+            Combatant::Player => &self.player,
+            Combatant::Enemy(index) => &self.enemies[index],
+        }
+    }
+
+    fn entity_mut(&mut self, combatant: Combatant) -> &mut dyn Entity {
+        match combatant {
+            Combatant::Player => &mut self.player,
+            Combatant::Enemy(index) => &mut self.enemies[index],
+        }
+    }
+
+    /// Quem recebe os golpes e efeitos de um lado: o alvo escolhido, para o jogador, ou o jogador, para os inimigos
+    fn opponent(&self, combatant: Combatant) -> Combatant {
+        match combatant {
+            Combatant::Player => Combatant::Enemy(self.target),
+            Combatant::Enemy(_) => Combatant::Player,
+        }
+    }
+
+    pub fn target(&self) -> usize {
+        self.target
+    }
+
+    pub fn is_alive(&self, enemy: usize) -> bool {
+        self.enemies.get(enemy).is_some_and(|enemy| enemy.health > 0)
+    }
+
+    pub fn living_enemies(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.enemies.len()).filter(|index| self.is_alive(*index))
+    }
+
+    /// Próximo inimigo vivo depois do alvo atual, em qualquer direção, dando a volta na fila
+    pub fn cycle_target(&self, forward: bool) -> Option<usize> {
+        let count = self.enemies.len();
+        (1..count)
+            .map(|step| if forward { (self.target + step) % count } else { (self.target + count - step) % count })
+            .find(|index| self.is_alive(*index))
+    }
+
+    /// Indica se um efeito de status faz o lado perder o turno
+    pub fn is_stunned(&self, combatant: Combatant) -> bool {
+        self.statuses.skips_turn(self.entity(combatant).status_effects())
+    }
+
+    /// Gravação da batalha até agora: estado inicial, semente e ações aplicadas
+    pub fn replay(&self) -> &BattleReplay {
+        &self.replay
+    }
+
+    pub fn outcome(&self) -> Option<BattleOutcome> {
+        self.outcome
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// De quem é a vez; no turno dos inimigos, o próximo da fila (ou o último, se todos já agiram)
+    pub fn current_actor(&self) -> Combatant {
+        if self.turn.player_turn() {
+            Combatant::Player
+        } else {
+            Combatant::Enemy(self.enemy_to_act().unwrap_or(self.enemies.len().saturating_sub(1)))
+        }
+    }
+
+    /// Inimigo vivo que ainda não agiu neste turno dos inimigos
+    pub fn enemy_to_act(&self) -> Option<usize> {
+        if self.turn.player_turn() {
+            return None;
+        }
+        (self.next_enemy..self.enemies.len()).find(|index| self.is_alive(*index))
+    }
+
+    /// Indica se o jogador ainda tem alguma carta na mão, na pilha de compra ou no descarte
+    pub fn player_has_cards(&self) -> bool {
+        !self.player.hand.cards.is_empty() || self.piles.has_cards()
+    }
+
+    /// Verifica se a carta da mão pode ser usada com a energia restante
+    pub fn can_play_card(&self, index: usize) -> bool {
+        self.player.hand.cards.get(index).is_some_and(|card| self.turn.can_afford(card.cost))
+    }
+
+    /// Indica se o jogador ainda pode agir neste turno: é a vez dele e alguma carta cabe na energia
+    pub fn player_can_act(&self) -> bool {
+        self.current_actor() == Combatant::Player
+            && !self.is_stunned(Combatant::Player)
+            && (0..self.player.hand.cards.len()).any(|index| self.can_play_card(index))
+    }
+
+    /// O que um inimigo vai fazer no próximo turno e quanto dano isso deve causar
+    pub fn enemy_intent(&self, index: usize) -> Option<EnemyIntent> {
+        let enemy = self.enemies.get(index)?;
+        let card = enemy.intent_card()?.clone();
+        let (base, hits) = match card.card_type {
+            CardType::Strike(damage) | CardType::Pierce(damage) => (damage, 1),
+            CardType::MultiHit(damage, times) => (damage, times),
+            CardType::AttackBasic(amount) | CardType::AttackStrong(amount) => (enemy.attack() + amount, 1),
+            _ if card.card_type.triggers_strike() => (enemy.attack(), 1),
+            _ => (0, 0),
+        };
+        let multiplier = self.statuses.damage_dealt_multiplier(enemy.status_effects())
+            * self.statuses.damage_taken_multiplier(self.player.status_effects());
+        let damage = (base as f32 * multiplier) as u32;
+        Some(EnemyIntent { card, damage, hits })
+    }
+
+    /// Resolve uma ação e devolve os eventos gerados. Ações fora de hora são ignoradas.
+    pub fn apply(&mut self, action: BattleAction) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        if self.is_over() {
+            return events;
+        }
+
+        match action {
+            BattleAction::PlayCard(index) => {
+                if self.current_actor() == Combatant::Player {
+                    self.play_player_card(index, &mut events);
+                }
+            }
+            BattleAction::EnemyAct => self.play_enemy_card(&mut events),
+            BattleAction::EndTurn => self.end_turn(&mut events),
+            BattleAction::SelectTarget(index) => {
+                if index != self.target && self.is_alive(index) {
+                    self.target = index;
+                    events.push(BattleEvent::TargetSelected(index));
+                }
+            }
+        }
+
+        if !self.is_over() {
+            self.advance_boss_phases(&mut events);
+        }
+
+        if !events.is_empty() {
+            self.replay.actions.push(action);
+        }
+        events
+    }
+
+    fn play_player_card(&mut self, index: usize, events: &mut Vec<BattleEvent>) {
+        if !self.can_play_card(index) {
+            return;
+        }
+        let Some(card) = self.player.hand.discard(index) else {
+            return;
+        };
+        self.turn.spend_energy(card.cost);
+
+        self.piles.put_away(card.clone());
+        if card.exhaust {
+            events.push(BattleEvent::CardExhausted(card.clone()));
+        }
+        let triggers_strike = card.card_type.triggers_strike();
+        self.resolve_card(Combatant::Player, card, events);
+        self.draw_player_card(events);
+        if triggers_strike && !self.is_over() {
+            self.strike(Combatant::Player, events);
+        }
+
+        if !self.is_over() && !self.player_can_act() && !self.player.hand.cards.is_empty() {
+            events.push(BattleEvent::OutOfEnergy);
+        }
+    }
+
+    fn draw_player_card(&mut self, events: &mut Vec<BattleEvent>) {
+        let (card, reshuffled) = self.piles.draw(&mut self.rng);
+        if reshuffled > 0 {
+            events.push(BattleEvent::Reshuffled { cards: reshuffled });
+        }
+        if let Some(card) = card {
+            self.player.hand.cards.push(card);
+        }
+    }
+
+    /// A IA de cada inimigo vivo escolhe a carta do próximo turno, se ainda não houver uma anunciada
+    fn plan_enemy_turns(&mut self) {
+        for index in 0..self.enemies.len() {
+            if !self.is_alive(index) || self.enemies[index].intent.is_some() {
+                continue;
+            }
+            let context = AiContext { enemy: &self.enemies[index], player: &self.player, statuses: &self.statuses };
+            self.enemies[index].intent = self.enemy_ais[index].choose_card(&context, &mut self.rng);
+        }
+    }
+
+    /// O próximo inimigo da fila usa uma carta; inimigos não gastam energia
+    fn play_enemy_card(&mut self, events: &mut Vec<BattleEvent>) {
+        let Some(index) = self.enemy_to_act() else {
+            return;
+        };
+        self.next_enemy = index + 1;
+        let actor = Combatant::Enemy(index);
+
+        if self.is_stunned(actor) {
+            events.push(BattleEvent::Stunned(actor));
+            return;
+        }
+        let Some(card) = self.enemies[index].play_card(&mut self.rng) else {
+            events.push(BattleEvent::NoCards(actor));
+            return;
+        };
+
+        let triggers_strike = card.card_type.triggers_strike();
+        self.resolve_card(actor, card, events);
+        if triggers_strike && !self.is_over() {
+            self.strike(actor, events);
+        }
+    }
+
+    fn resolve_card(&mut self, actor: Combatant, card: Card, events: &mut Vec<BattleEvent>) {
+        let card_type = card.card_type.clone();
+        let duration_bonus = if card.upgraded { UPGRADE_STATUS_DURATION_BONUS } else { 0 };
+        events.push(BattleEvent::CardPlayed { actor, card });
+
+        match card_type {
+            CardType::AttackBasic(amount) | CardType::AttackStrong(amount) => {
+                self.entity_mut(actor).attack_up(amount);
+                events.push(BattleEvent::AttackUp { actor, amount });
+            }
+            CardType::Defense(amount) => {
+                self.entity_mut(actor).defense_up(amount);
+                events.push(BattleEvent::DefenseUp { actor, amount });
+            }
+            CardType::Poison(_) => {
+                self.inflict(actor, StatusEffect::Poison, STATUS_EFFECT_DURATION_POISON + duration_bonus, events);
+            }
+            CardType::Burn(_) => {
+                self.inflict(actor, StatusEffect::Burn, STATUS_EFFECT_DURATION_BURN + duration_bonus, events);
+            }
+            CardType::ApplyStatus(effect, duration) => {
+                self.inflict(actor, effect, duration, events);
+            }
+            CardType::Heal(heal_percent) => {
+                let user = self.entity_mut(actor);
+                let amount = (user.max_health() as f32 * heal_percent) as u32;
+                user.heal(amount);
+                events.push(BattleEvent::Healed { target: actor, amount });
+            }
+            CardType::Block(amount) => {
+                self.entity_mut(actor).shield_up(amount);
+                events.push(BattleEvent::ShieldUp { actor, amount });
+            }
+            CardType::Strike(damage) => {
+                self.hit(actor, damage, false, events);
+                self.check_defeat(actor, events);
+            }
+            CardType::Pierce(damage) => {
+                self.hit(actor, damage, true, events);
+                self.check_defeat(actor, events);
+            }
+            CardType::MultiHit(damage, times) => {
+                for _ in 0..times {
+                    // Golpes seguintes vão para o novo alvo se o anterior cair
+                    if self.entity(self.opponent(actor)).health() == 0 {
+                        break;
+                    }
+                    self.hit(actor, damage, false, events);
+                    if self.check_defeat(actor, events) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Aplica um efeito de status: benéficos em quem usou a carta, os outros no oponente
+    fn inflict(&mut self, actor: Combatant, effect: StatusEffect, duration: u32, events: &mut Vec<BattleEvent>) {
+        let Some(definition) = self.statuses.get(effect) else {
+            return;
+        };
+        let target = if definition.beneficial { actor } else { self.opponent(actor) };
+        self.apply_status(target, effect, duration, events);
+    }
+
+    fn apply_status(&mut self, target: Combatant, effect: StatusEffect, duration: u32, events: &mut Vec<BattleEvent>) {
+        let Some(definition) = self.statuses.get(effect) else {
+            return;
+        };
+        let stacking = definition.stacking;
+
+        self.entity_mut(target).status_effect(effect, duration, stacking);
+        events.push(BattleEvent::StatusApplied { target, effect, duration });
+    }
+
+    /// Um golpe no oponente; `pierce` ignora a defesa, mas não o escudo.
+    /// Fraqueza e vulnerabilidade alteram o valor, e espinhos devolvem dano ao atacante.
+    fn hit(&mut self, attacker: Combatant, damage: u32, pierce: bool, events: &mut Vec<BattleEvent>) {
+        let target = self.opponent(attacker);
+        let multiplier = self.statuses.damage_dealt_multiplier(self.entity(attacker).status_effects())
+            * self.statuses.damage_taken_multiplier(self.entity(target).status_effects());
+        let damage = (damage as f32 * multiplier) as u32;
+        let thorns = self.statuses.thorns(self.entity(target).status_effects());
+
+        let opponent = self.entity_mut(target);
+        let health_before = opponent.health();
+        let blocked = if pierce { opponent.pierce(damage) } else { opponent.damage(damage) };
+        let actual = health_before - opponent.health();
+        events.push(BattleEvent::Damage { attacker, target, damage, actual, blocked });
+
+        if thorns > 0 {
+            let user = self.entity_mut(attacker);
+            let health_before = user.health();
+            user.set_health(health_before.saturating_sub(thorns));
+            events.push(BattleEvent::StatusTick {
+                target: attacker,
+                effect: StatusEffect::Thorns,
+                damage: health_before - user.health(),
+                healed: 0,
+            });
+        }
+    }
+
+    /// Dispara os efeitos de status de um combatente no início ou no fim do turno dele
+    fn trigger_statuses(&mut self, target: Combatant, trigger: StatusTrigger, events: &mut Vec<BattleEvent>) {
+        let outcomes = match target {
+            Combatant::Player => self.player.trigger_status_effects(&self.statuses, trigger),
+            Combatant::Enemy(index) => self.enemies[index].trigger_status_effects(&self.statuses, trigger),
+        };
+
+        for outcome in outcomes {
+            if outcome.damage > 0 || outcome.healed > 0 {
+                events.push(BattleEvent::StatusTick {
+                    target,
+                    effect: outcome.effect,
+                    damage: outcome.damage,
+                    healed: outcome.healed,
+                });
+            }
+            if outcome.expired {
+                events.push(BattleEvent::StatusExpired { target, effect: outcome.effect });
+            }
+        }
+    }
+
+    /// Começa as fases de chefe cujo limite de vida já foi atingido
+    fn advance_boss_phases(&mut self, events: &mut Vec<BattleEvent>) {
+        for index in 0..self.enemies.len() {
+            while let Some(phase) = self.enemies[index].phases.get(self.enemies[index].phase).cloned() {
+                let enemy = &mut self.enemies[index];
+                let threshold = (enemy.max_health as f32 * phase.health_threshold) as u32;
+                if enemy.health == 0 || enemy.health > threshold {
+                    break;
+                }
+                enemy.phase += 1;
+                events.push(BattleEvent::PhaseChanged { enemy: index, phase: enemy.phase, name: phase.name.clone() });
+
+                if let Some(ai) = &phase.ai {
+                    enemy.ai = ai.clone();
+                    self.enemy_ais[index] = ai.build();
+                }
+                if let Some(pool) = &phase.card_pool {
+                    enemy.card_pool = pool.clone();
+                    enemy.hand = Hand::new();
+                    enemy.hand.draw_with_chances(pool, INITIAL_HAND_SIZE, &mut self.rng);
+                }
+                if phase.heal_percent > 0.0 {
+                    let health_before = enemy.health;
+                    enemy.heal((enemy.max_health as f32 * phase.heal_percent) as u32);
+                    events.push(BattleEvent::Healed { target: Combatant::Enemy(index), amount: enemy.health - health_before });
+                }
+                // A jogada anunciada foi pensada para a fase anterior
+                enemy.intent = None;
+
+                for field in &phase.field_effects {
+                    let targets: &[Combatant] = match field.target {
+                        FieldTarget::Player => &[Combatant::Player],
+                        FieldTarget::Boss => &[Combatant::Enemy(index)],
+                        FieldTarget::Both => &[Combatant::Player, Combatant::Enemy(index)],
+                    };
+                    for target in targets {
+                        self.apply_status(*target, field.effect, field.duration, events);
+                    }
+                }
+                for minion in &phase.summons {
+                    self.summon(index, minion.clone(), events);
+                }
+
+                if self.turn.player_turn() {
+                    self.plan_enemy_turns();
+                }
+            }
+        }
+    }
+
+    /// Põe um reforço na vaga de um inimigo caído ou no fim da fila, sem passar do limite do encontro
+    fn summon(&mut self, summoner: usize, mut minion: Enemy, events: &mut Vec<BattleEvent>) {
+        let free_slot = (0..self.enemies.len()).find(|index| !self.is_alive(*index));
+        if free_slot.is_none() && self.enemies.len() >= MAX_ENCOUNTER_ENEMIES {
+            return;
+        }
+        minion.hand = Hand::new();
+        minion.hand.draw_with_chances(&minion.card_pool, INITIAL_HAND_SIZE, &mut self.rng);
+        let ai = minion.ai.build();
+
+        let slot = match free_slot {
+            Some(slot) => {
+                self.enemies[slot] = minion;
+                self.enemy_ais[slot] = ai;
+                self.fallen[slot] = false;
+                slot
+            }
+            None => {
+                self.enemies.push(minion);
+                self.enemy_ais.push(ai);
+                self.fallen.push(false);
+                self.enemies.len() - 1
+            }
+        };
+        events.push(BattleEvent::Summoned { summoner, enemy: slot });
+    }
+
+    /// Anuncia os inimigos que caíram e encerra a batalha se o jogador caiu ou não sobrou inimigo.
+    /// Se os dois lados caírem no mesmo golpe, vence quem atacou.
+    fn check_defeat(&mut self, attacker: Combatant, events: &mut Vec<BattleEvent>) -> bool {
+        for index in 0..self.enemies.len() {
+            if !self.fallen[index] && !self.is_alive(index) {
+                self.fallen[index] = true;
+                self.enemies[index].intent = None;
+                events.push(BattleEvent::EnemyDefeated(index));
+            }
+        }
+        let next_target = self.living_enemies().next();
+        if let (false, Some(next)) = (self.is_alive(self.target), next_target) {
+            self.target = next;
+            events.push(BattleEvent::TargetSelected(next));
+        }
+
+        let player_fallen = self.player.health() == 0;
+        let enemies_fallen = self.living_enemies().next().is_none();
+        let outcome = match (player_fallen, enemies_fallen) {
+            (false, false) => return false,
+            (true, true) if attacker == Combatant::Player => BattleOutcome::PlayerWon,
+            (true, _) => BattleOutcome::EnemyWon,
+            (false, true) => BattleOutcome::PlayerWon,
+        };
+        self.outcome = Some(outcome);
+        events.push(BattleEvent::BattleEnded(outcome));
+        true
+    }
+
+    /// Ataque que segue as cartas de bônus, status e cura
+    fn strike(&mut self, attacker: Combatant, events: &mut Vec<BattleEvent>) {
+        let damage = self.entity(attacker).attack();
+        self.hit(attacker, damage, false, events);
+        self.check_defeat(attacker, events);
+    }
+
+    /// Combatentes vivos de um lado: o jogador, ou os inimigos na ordem do encontro
+    fn side(&self, player_side: bool) -> Vec<Combatant> {
+        if player_side {
+            vec![Combatant::Player]
+        } else {
+            self.living_enemies().map(Combatant::Enemy).collect()
+        }
+    }
+
+    fn end_turn(&mut self, events: &mut Vec<BattleEvent>) {
+        let player_side = self.turn.player_turn();
+        // Quem causa o dano dos efeitos de status é o lado oposto
+        let other_side = if player_side { Combatant::Enemy(self.target) } else { Combatant::Player };
+
+        for actor in self.side(player_side) {
+            self.trigger_statuses(actor, StatusTrigger::TurnEnd, events);
+        }
+        if self.check_defeat(other_side, events) {
+            return;
+        }
+
+        for actor in self.side(player_side) {
+            for buff in self.entity_mut(actor).tick_buffs() {
+                events.push(BattleEvent::BuffExpired { target: actor, stat: buff.stat, amount: buff.amount });
+            }
+        }
+
+        self.turn.next_turn();
+        self.next_enemy = 0;
+
+        // O escudo dura só até o próximo turno de quem o ergueu
+        let next_side = self.side(!player_side);
+        for next in &next_side {
+            let entity = self.entity_mut(*next);
+            let shield = entity.modifiers().shield;
+            if shield > 0 {
+                entity.modifiers_mut().shield = 0;
+                events.push(BattleEvent::ShieldExpired { target: *next, amount: shield });
+            }
+        }
+
+        if self.turn.turn_over() {
+            self.outcome = Some(BattleOutcome::Draw);
+            events.push(BattleEvent::BattleEnded(BattleOutcome::Draw));
+            return;
+        }
+
+        events.push(BattleEvent::TurnStarted(self.current_actor()));
+        for next in &next_side {
+            self.trigger_statuses(*next, StatusTrigger::TurnStart, events);
+        }
+        let other_side = if player_side { Combatant::Player } else { Combatant::Enemy(self.target) };
+        if self.check_defeat(other_side, events) {
+            return;
+        }
+        if !player_side {
+            // Os inimigos decidem a próxima jogada no início do turno do jogador
+            self.plan_enemy_turns();
+            // O inimigo atordoado avisa ao tentar agir; o jogador fica sabendo já no início do turno
+            if self.is_stunned(Combatant::Player) {
+                events.push(BattleEvent::Stunned(Combatant::Player));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::deck::CardPool;
+    use crate::boss::{BossPhase, FieldEffect};
+    use crate::effects::Stacking;
+    use crate::enemy_ai::EnemyAiKind;
+
+    const SEED: u64 = 42;
+
+    fn card(id: &str, card_type: CardType, cost: u32) -> Card {
+        Card {
+            id: id.to_string(),
+            name: id.to_string(),
+            card_type,
+            image_path: String::new(),
+            rarity: Default::default(),
+            cost,
+            exhaust: false,
+            upgraded: false,
+        }
+    }
+
+    fn enemy(health: u32, defense: u32) -> Enemy {
+        let pool = CardPool { entries: vec![(card("guard", CardType::Block(1), 1), 1.0)] };
+        Enemy::new("Dummy", health, health, 5, defense, "", pool)
+    }
+
+    fn engine_with(deck: Vec<Card>, enemies: Vec<Enemy>) -> BattleEngine {
+        BattleEngine::new(
+            Player::new("Tester"),
+            enemies,
+            Deck { cards: deck },
+            StatusRegistry::embedded(),
+            MAX_TURNS,
+            SEED,
+        )
+    }
+
+    /// Joga a batalha sozinho: a primeira carta que couber na energia, senão passa a vez
+    pub(crate) fn auto_play(engine: &mut BattleEngine, steps: usize) {
+        for _ in 0..steps {
+            let action = match engine.current_actor() {
+                Combatant::Player if engine.player_can_act() => {
+                    let index = (0..engine.player.hand.cards.len()).find(|index| engine.can_play_card(*index));
+                    BattleAction::PlayCard(index.unwrap_or(0))
+                }
+                Combatant::Enemy(_) if engine.enemy_to_act().is_some() => BattleAction::EnemyAct,
+                _ => BattleAction::EndTurn,
+            };
+            engine.apply(action);
+            if engine.is_over() {
+                break;
+            }
+        }
+    }
+
+    fn strikes(count: usize, damage: u32) -> Vec<Card> {
+        (0..count).map(|_| card("strike", CardType::Strike(damage), 1)).collect()
+    }
+
+    #[test]
+    fn card_spends_energy_and_damage_goes_through_defense_and_shield() {
+        let mut engine = engine_with(strikes(10, 20), vec![enemy(100, 2)]);
+        engine.enemies[0].modifiers.shield = 5;
+
+        let events = engine.apply(BattleAction::PlayCard(0));
+
+        assert_eq!(engine.turn.energy, PLAYER_MAX_ENERGY - 1);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            BattleEvent::Damage { target: Combatant::Enemy(0), damage: 20, actual: 13, blocked: 5, .. }
+        )));
+        assert_eq!(engine.enemies[0].health, 87);
+        assert_eq!(engine.enemies[0].modifiers.shield, 0);
+        assert_eq!(engine.player.hand.cards.len(), INITIAL_HAND_SIZE);
+    }
+
+    #[test]
+    fn pierce_ignores_defense() {
+        let mut engine = engine_with(strikes(10, 20), vec![enemy(100, 8)]);
+        engine.player.hand.cards[0] = card("pierce", CardType::Pierce(12), 2);
+
+        engine.apply(BattleAction::PlayCard(0));
+
+        assert_eq!(engine.enemies[0].health, 88);
+        assert_eq!(engine.turn.energy, PLAYER_MAX_ENERGY - 2);
+    }
+
+    #[test]
+    fn card_without_energy_is_ignored() {
+        let mut engine = engine_with(strikes(10, 20), vec![enemy(100, 0)]);
+        engine.player.hand.cards[0] = card("expensive", CardType::Strike(50), PLAYER_MAX_ENERGY + 1);
+
+        assert!(engine.apply(BattleAction::PlayCard(0)).is_empty());
+        assert_eq!(engine.enemies[0].health, 100);
+        assert!(engine.replay().actions.is_empty());
+    }
+
+    #[test]
+    fn finishing_blow_ends_the_battle() {
+        let mut engine = engine_with(strikes(10, 20), vec![enemy(15, 0)]);
+
+        let events = engine.apply(BattleAction::PlayCard(0));
+
+        assert!(events.iter().any(|event| matches!(event, BattleEvent::EnemyDefeated(0))));
+        assert!(matches!(events.last(), Some(BattleEvent::BattleEnded(BattleOutcome::PlayerWon))));
+        assert_eq!(engine.outcome(), Some(BattleOutcome::PlayerWon));
+        assert!(engine.apply(BattleAction::PlayCard(0)).is_empty());
+    }
+
+    #[test]
+    fn empty_draw_pile_reshuffles_the_discard() {
+        let mut engine = engine_with(strikes(INITIAL_HAND_SIZE, 1), vec![enemy(100, 50)]);
+        assert!(engine.piles.draw.cards.is_empty());
+        engine.piles.discard = strikes(2, 1);
+
+        let events = engine.apply(BattleAction::PlayCard(0));
+
+        assert!(events.iter().any(|event| matches!(event, BattleEvent::Reshuffled { cards: 3 })));
+        assert!(engine.piles.discard.is_empty());
+        assert_eq!(engine.piles.draw.cards.len(), 2);
+        assert_eq!(engine.player.hand.cards.len(), INITIAL_HAND_SIZE);
+    }
+
+    #[test]
+    fn end_turn_ticks_statuses_of_both_sides() {
+        let mut engine = engine_with(strikes(10, 1), vec![enemy(100, 0)]);
+        let burn = engine.statuses().get(StatusEffect::Burn).unwrap().tick_damage;
+        let poison = engine.statuses().get(StatusEffect::Poison).unwrap().tick_damage;
+        engine.player.status_effect(StatusEffect::Burn, 2, Stacking::Duration);
+        engine.enemies[0].status_effect(StatusEffect::Poison, 1, Stacking::Duration);
+
+        let events = engine.apply(BattleAction::EndTurn);
+
+        // Queimadura dispara no fim do turno de quem a sofre, veneno no começo
+        assert!(events.iter().any(|event| matches!(
+            event,
+            BattleEvent::StatusTick { target: Combatant::Player, effect: StatusEffect::Burn, .. }
+        )));
+        assert_eq!(engine.player.health, 100 - burn);
+        assert_eq!(engine.player.status_effects[&StatusEffect::Burn].duration, 1);
+        assert_eq!(engine.enemies[0].health, 100 - poison);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            BattleEvent::StatusExpired { target: Combatant::Enemy(0), effect: StatusEffect::Poison }
+        )));
+        assert_eq!(engine.current_actor(), Combatant::Enemy(0));
+        assert_eq!(engine.turn.energy, PLAYER_MAX_ENERGY);
+    }
+
+    fn phase(name: &str, health_threshold: f32) -> BossPhase {
+        BossPhase {
+            name: name.to_string(),
+            health_threshold,
+            ai: None,
+            card_pool: None,
+            heal_percent: 0.0,
+            field_effects: Vec::new(),
+            summons: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn boss_changes_phase_when_health_crosses_the_threshold() {
+        let mut boss = enemy(100, 0);
+        let mut enraged = phase("Enraged", 0.5);
+        enraged.heal_percent = 0.1;
+        enraged.ai = Some(EnemyAiKind::Scripted(vec!["guard".to_string()]));
+        enraged.field_effects.push(FieldEffect { effect: StatusEffect::Weak, duration: 2, target: FieldTarget::Player });
+        boss.phases = vec![enraged, phase("Desperate", 0.2)];
+        let mut engine = engine_with(strikes(10, 30), vec![boss]);
+
+        let events = engine.apply(BattleAction::PlayCard(0));
+        assert!(!events.iter().any(|event| matches!(event, BattleEvent::PhaseChanged { .. })));
+
+        let events = engine.apply(BattleAction::PlayCard(0));
+        assert!(events.iter().any(|event| matches!(event, BattleEvent::PhaseChanged { enemy: 0, phase: 1, .. })));
+        assert_eq!(engine.enemies[0].phase, 1);
+        assert_eq!(engine.enemies[0].health, 50);
+        assert_eq!(engine.enemies[0].ai, EnemyAiKind::Scripted(vec!["guard".to_string()]));
+        assert!(engine.player.status_effects.contains_key(&StatusEffect::Weak));
+        // A jogada anunciada é refeita para a fase nova
+        assert_eq!(engine.enemy_intent(0).unwrap().card.id, "guard");
+
+        // Fraqueza reduz o golpe seguinte: 30 * 0.75
+        engine.apply(BattleAction::PlayCard(0));
+        assert_eq!(engine.enemies[0].health, 28);
+        assert_eq!(engine.enemies[0].phase, 1);
+    }
+
+    #[test]
+    fn defeated_target_passes_to_the_next_living_enemy() {
+        let mut engine = engine_with(strikes(10, 30), vec![enemy(20, 0), enemy(100, 0), enemy(100, 0)]);
+
+        assert_eq!(engine.cycle_target(true), Some(1));
+        assert_eq!(engine.cycle_target(false), Some(2));
+        let events = engine.apply(BattleAction::SelectTarget(2));
+        assert!(matches!(events.as_slice(), [BattleEvent::TargetSelected(2)]));
+        engine.apply(BattleAction::PlayCard(0));
+        assert_eq!(engine.enemies[2].health, 70);
+
+        engine.apply(BattleAction::SelectTarget(0));
+        let events = engine.apply(BattleAction::PlayCard(0));
+        assert!(events.iter().any(|event| matches!(event, BattleEvent::EnemyDefeated(0))));
+        assert_eq!(engine.target(), 1);
+        assert!(!engine.is_over());
+        // Inimigo caído não pode ser escolhido de novo
+        assert!(engine.apply(BattleAction::SelectTarget(0)).is_empty());
+        assert_eq!(engine.cycle_target(true), Some(2));
+    }
+
+    #[test]
+    fn summons_reuse_fallen_slots_and_respect_the_encounter_limit() {
+        let mut boss = enemy(100, 0);
+        let mut call = phase("Call", 0.9);
+        call.summons = vec![enemy(10, 0), enemy(10, 0), enemy(10, 0)];
+        let mut call_again = phase("Call again", 0.5);
+        call_again.summons = vec![enemy(10, 0)];
+        boss.phases = vec![call, call_again];
+        let mut engine = engine_with(strikes(10, 30), vec![boss, enemy(5, 0)]);
+
+        // O primeiro golpe derruba a escolta; os três reforços só cabem na vaga dela e em mais uma
+        engine.apply(BattleAction::SelectTarget(1));
+        engine.apply(BattleAction::PlayCard(0));
+        engine.apply(BattleAction::SelectTarget(0));
+        let events = engine.apply(BattleAction::PlayCard(0));
+        let summoned: Vec<usize> = events
+            .iter()
+            .filter_map(|event| match event {
+                BattleEvent::Summoned { enemy, .. } => Some(*enemy),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(summoned, [1, 2]);
+        assert_eq!(engine.enemies.len(), MAX_ENCOUNTER_ENEMIES);
+        assert_eq!(engine.living_enemies().count(), MAX_ENCOUNTER_ENEMIES);
+
+        // Com a fila cheia e todos vivos, a fase seguinte não chama ninguém
+        let events = engine.apply(BattleAction::PlayCard(0));
+        assert!(events.iter().any(|event| matches!(event, BattleEvent::PhaseChanged { phase: 2, .. })));
+        assert!(!events.iter().any(|event| matches!(event, BattleEvent::Summoned { .. })));
+        assert_eq!(engine.enemies.len(), MAX_ENCOUNTER_ENEMIES);
+    }
+
+    #[test]
+    fn upgraded_poison_keeps_its_cost_and_lasts_longer() {
+        let mut poison = card("poison", CardType::Poison(5), 1);
+        poison.upgrade();
+        assert_eq!(poison.cost, 1);
+        assert_eq!(poison.name, "poison+");
+
+        let mut engine = engine_with(strikes(10, 1), vec![enemy(100, 50)]);
+        engine.player.hand.cards[0] = poison;
+        engine.apply(BattleAction::PlayCard(0));
+
+        assert_eq!(engine.turn.energy, PLAYER_MAX_ENERGY - 1);
+        let duration = engine.enemies[0].status_effects[&StatusEffect::Poison].duration;
+        assert_eq!(duration, STATUS_EFFECT_DURATION_POISON + UPGRADE_STATUS_DURATION_BONUS);
+    }
+
+    #[test]
+    fn saved_battle_continues_exactly_like_the_original() {
+        let mut scripted = enemy(300, 0);
+        scripted.card_pool.entries.push((card("jab", CardType::Strike(2), 1), 1.0));
+        scripted.ai = EnemyAiKind::Scripted(vec!["jab".to_string(), "guard".to_string(), "guard".to_string()]);
+        let mut engine = engine_with(strikes(12, 3), vec![scripted, enemy(300, 0)]);
+        auto_play(&mut engine, 15);
+        assert!(!engine.is_over());
+        assert!(engine.enemy_ais[0].progress() > 0);
+
+        let json = serde_json::to_string(&engine).unwrap();
+        let mut restored: BattleEngine = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+        assert_eq!(restored.enemy_ais[0].progress(), engine.enemy_ais[0].progress());
+        assert_eq!(restored.replay().actions, engine.replay().actions);
+
+        auto_play(&mut engine, 200);
+        auto_play(&mut restored, 200);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), serde_json::to_string(&engine).unwrap());
+        assert_eq!(restored.outcome(), engine.outcome());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::card_catalog::CardCatalog;
+use crate::deck::{CardPool, EnemyCardChances};
+use crate::effects::StatusEffect;
+use crate::enemy::Enemy;
+use crate::enemy_ai::EnemyAiKind;
+use crate::enemy_roster::EnemyRoster;
+use serde::{Deserialize, Serialize};
+
+/// Quem recebe um efeito de campo ao começar uma fase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldTarget {
+    Player,
+    Boss,
+    Both,
+}
+
+/// Efeito de status aplicado pela própria fase, sem depender de carta
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldEffect {
+    pub effect: StatusEffect,
+    pub duration: u32,
+    pub target: FieldTarget,
+}
+
+/// Fase de chefe no arquivo de dados; começa quando a vida cai até `health_threshold` da máxima
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossPhaseDefinition {
+    pub name: String,
+    pub health_threshold: f32,
+    #[serde(default)]
+    pub ai: Option<EnemyAiKind>,
+    /// Nova tabela de chances; a mão do chefe é trocada por uma comprada dela
+    #[serde(default)]
+    pub card_chances: Option<EnemyCardChances>,
+    /// Cura ao entrar na fase, em fração da vida máxima
+    #[serde(default)]
+    pub heal_percent: f32,
+    #[serde(default)]
+    pub field_effects: Vec<FieldEffect>,
+    /// Ids dos inimigos do roster chamados como reforço
+    #[serde(default)]
+    pub summons: Vec<u32>,
+}
+
+impl BossPhaseDefinition {
+    /// Fase pronta para a batalha, com as cartas e os reforços já buscados no catálogo e no roster
+    pub fn to_phase(&self, catalog: &CardCatalog, roster: &EnemyRoster) -> BossPhase {
+        BossPhase {
+            name: self.name.clone(),
+            health_threshold: self.health_threshold,
+            ai: self.ai.clone(),
+            card_pool: self.card_chances.as_ref().map(|chances| chances.card_pool(catalog)),
+            heal_percent: self.heal_percent,
+            field_effects: self.field_effects.clone(),
+            summons: self
+                .summons
+                .iter()
+                .filter_map(|id| roster.get(*id))
+                .map(|definition| definition.to_enemy(catalog))
+                .collect(),
+        }
+    }
+}
+
+/// Fase de chefe usada pelo motor de batalha
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BossPhase {
+    pub name: String,
+    pub health_threshold: f32,
+    pub ai: Option<EnemyAiKind>,
+    pub card_pool: Option<CardPool>,
+    pub heal_percent: f32,
+    pub field_effects: Vec<FieldEffect>,
+    #[serde(default)]
+    pub summons: Vec<Enemy>,
+}
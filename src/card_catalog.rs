@@ -0,0 +1,119 @@
+use crate::config::config::*;
+use crate::deck::{default_card_cost, Card, CardType};
+use crate::error::{GameError, GameResult};
+use macroquad::file::load_string;
+use serde::{Deserialize, Serialize};
+
+/// Raridade de uma carta, usada para sorteios e destaque visual
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+}
+
+impl Rarity {
+    /// Limite de cópias de uma carta dessa raridade no baralho
+    pub fn max_copies(self) -> u32 {
+        match self {
+            Rarity::Common => MAX_COPIES_COMMON,
+            Rarity::Uncommon => MAX_COPIES_UNCOMMON,
+            Rarity::Rare => MAX_COPIES_RARE,
+            Rarity::Epic => MAX_COPIES_EPIC,
+        }
+    }
+
+    /// Preço de uma carta dessa raridade na loja
+    pub fn shop_price(self) -> u32 {
+        match self {
+            Rarity::Common => SHOP_PRICE_COMMON,
+            Rarity::Uncommon => SHOP_PRICE_UNCOMMON,
+            Rarity::Rare => SHOP_PRICE_RARE,
+            Rarity::Epic => SHOP_PRICE_EPIC,
+        }
+    }
+}
+
+/// Definição de uma carta no arquivo de dados
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardDefinition {
+    pub id: String,
+    pub name: String,
+    pub card_type: CardType,
+    pub image: String,
+    #[serde(default)]
+    pub rarity: Rarity,
+    #[serde(default = "default_card_cost")]
+    pub cost: u32,
+    #[serde(default)]
+    pub exhaust: bool,
+    /// Quantas cópias entram no baralho inicial do jogador
+    #[serde(default)]
+    pub starter_copies: u32,
+}
+
+impl CardDefinition {
+    pub fn to_card(&self) -> Card {
+        Card {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            card_type: self.card_type.clone(),
+            image_path: self.image.clone(),
+            rarity: self.rarity,
+            cost: self.cost,
+            exhaust: self.exhaust,
+            upgraded: false,
+        }
+    }
+}
+
+/// Catálogo de todas as cartas do jogo, carregado de `assets/data/cards.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardCatalog {
+    pub cards: Vec<CardDefinition>,
+}
+
+impl CardCatalog {
+    pub const PATH: &'static str = "assets/data/cards.json";
+
+    pub fn from_json(json: &str) -> GameResult<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| GameError::AssetLoadError(format!("{}: {}", Self::PATH, e)))
+    }
+
+    /// Cópia do catálogo embutida no executável, usada se o arquivo não puder ser lido
+    pub fn embedded() -> Self {
+        Self::from_json(include_str!("../assets/data/cards.json"))
+            .expect("embedded card catalog must be valid")
+    }
+
+    pub async fn load() -> Self {
+        match load_string(Self::PATH).await {
+            Ok(json) => Self::from_json(&json).unwrap_or_else(|e| {
+                eprintln!("⚠️ {}, using built-in cards", e);
+                Self::embedded()
+            }),
+            Err(_) => Self::embedded(),
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&CardDefinition> {
+        self.cards.iter().find(|definition| definition.id == id)
+    }
+
+    pub fn card(&self, id: &str) -> Option<Card> {
+        self.get(id).map(CardDefinition::to_card)
+    }
+
+    /// Cartas do baralho inicial, na ordem do catálogo
+    pub fn starter_cards(&self) -> Vec<Card> {
+        self.cards
+            .iter()
+            .flat_map(|definition| {
+                (0..definition.starter_copies).map(move |_| definition.to_card())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,161 @@
+use crate::card_catalog::CardCatalog;
+use crate::config::config::*;
+use crate::deck::{Card, Deck};
+use crate::error::{GameError, GameResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Cartas que o jogador possui e as que escolheu para o baralho, contadas por id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardCollection {
+    pub owned: BTreeMap<String, u32>,
+    pub deck: BTreeMap<String, u32>,
+    /// Cartas melhoradas na loja; a melhoria vale para todas as cópias
+    #[serde(default)]
+    pub upgraded: BTreeSet<String>,
+}
+
+impl CardCollection {
+    /// Coleção inicial: as cartas do baralho padrão, todas já no baralho
+    pub fn starter(catalog: &CardCatalog) -> Self {
+        Self::from_cards(&Deck::new(catalog).cards)
+    }
+
+    /// Coleção em que todas as cartas possuídas estão no baralho, como o baralho de uma run
+    pub fn from_cards(cards: &[Card]) -> Self {
+        let mut owned = BTreeMap::new();
+        for card in cards {
+            *owned.entry(card.id.clone()).or_insert(0) += 1;
+        }
+
+        Self {
+            deck: owned.clone(),
+            owned,
+            upgraded: cards.iter().filter(|card| card.upgraded).map(|card| card.id.clone()).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.owned.is_empty()
+    }
+
+    pub fn owned_copies(&self, id: &str) -> u32 {
+        self.owned.get(id).copied().unwrap_or(0)
+    }
+
+    pub fn deck_copies(&self, id: &str) -> u32 {
+        self.deck.get(id).copied().unwrap_or(0)
+    }
+
+    pub fn deck_size(&self) -> u32 {
+        self.deck.values().sum()
+    }
+
+    pub fn add_card(&mut self, id: &str) {
+        *self.owned.entry(id.to_string()).or_insert(0) += 1;
+    }
+
+    /// Acrescenta uma cópia direto no baralho, sem limite de cópias; usado no baralho da run
+    pub fn add_card_to_deck(&mut self, id: &str) {
+        self.add_card(id);
+        *self.deck.entry(id.to_string()).or_insert(0) += 1;
+    }
+
+    /// Descarta uma cópia possuída; o baralho nunca fica com mais cópias do que as possuídas
+    pub fn remove_card(&mut self, id: &str) -> bool {
+        let Some(copies) = self.owned.get_mut(id).filter(|copies| **copies > 0) else {
+            return false;
+        };
+        *copies -= 1;
+        let remaining = *copies;
+        if remaining == 0 {
+            self.owned.remove(id);
+            self.upgraded.remove(id);
+        }
+        if self.deck_copies(id) > remaining {
+            self.remove_from_deck(id);
+        }
+        true
+    }
+
+    pub fn is_upgraded(&self, id: &str) -> bool {
+        self.upgraded.contains(id)
+    }
+
+    pub fn upgrade_card(&mut self, id: &str) -> bool {
+        self.owned_copies(id) > 0 && self.upgraded.insert(id.to_string())
+    }
+
+    /// Quantas cópias da carta podem ir para o baralho: o que o jogador possui, até o limite da raridade
+    pub fn copy_limit(&self, catalog: &CardCatalog, id: &str) -> u32 {
+        let rarity_limit = catalog.get(id).map_or(0, |definition| definition.rarity.max_copies());
+        self.owned_copies(id).min(rarity_limit)
+    }
+
+    pub fn add_to_deck(&mut self, catalog: &CardCatalog, id: &str) -> bool {
+        if self.deck_size() >= MAX_DECK_SIZE || self.deck_copies(id) >= self.copy_limit(catalog, id) {
+            return false;
+        }
+        *self.deck.entry(id.to_string()).or_insert(0) += 1;
+        true
+    }
+
+    pub fn remove_from_deck(&mut self, id: &str) -> bool {
+        match self.deck.get_mut(id) {
+            Some(copies) if *copies > 0 => {
+                *copies -= 1;
+                if *copies == 0 {
+                    self.deck.remove(id);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Confere tamanho do baralho, limites de cópias e cartas que não existem mais no catálogo
+    pub fn validate_deck(&self, catalog: &CardCatalog) -> GameResult<()> {
+        let size = self.deck_size();
+        if !(MIN_DECK_SIZE..=MAX_DECK_SIZE).contains(&size) {
+            return Err(GameError::InvalidGameState(format!(
+                "Deck must have {}-{} cards (has {})",
+                MIN_DECK_SIZE, MAX_DECK_SIZE, size
+            )));
+        }
+
+        for (id, &copies) in &self.deck {
+            let Some(definition) = catalog.get(id) else {
+                return Err(GameError::InvalidGameState(format!("Unknown card: {}", id)));
+            };
+            if copies > self.copy_limit(catalog, id) {
+                return Err(GameError::InvalidGameState(format!(
+                    "Too many copies of {} ({} > {})",
+                    definition.name,
+                    copies,
+                    self.copy_limit(catalog, id)
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Monta o baralho da batalha na ordem do catálogo; o motor embaralha depois
+    pub fn build_deck(&self, catalog: &CardCatalog) -> Deck {
+        let cards = catalog
+            .cards
+            .iter()
+            .flat_map(|definition| {
+                let upgraded = self.is_upgraded(&definition.id);
+                (0..self.deck_copies(&definition.id)).map(move |_| {
+                    let mut card = definition.to_card();
+                    if upgraded {
+                        card.upgrade();
+                    }
+                    card
+                })
+            })
+            .collect();
+        Deck { cards }
+    }
+}
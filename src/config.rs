@@ -5,6 +5,9 @@ pub mod config {
     pub const ENEMY_TURN_COOLDOWN: f32 = 1.0;
     pub const ENEMY_SHAKE_DURATION: f32 = 0.3;
     pub const FINAL_BLOW_DURATION: f32 = 1.0;
+    pub const REPLAY_STEP_DELAY: f32 = 0.8;
+    pub const CARD_PLAY_COOLDOWN: f32 = 0.4;
+    pub const PHASE_TRANSITION_DURATION: f32 = 1.5;
     
     // Configurações de tela
     pub const MIN_WINDOW_WIDTH: f32 = 800.0;
@@ -22,18 +25,83 @@ pub mod config {
     // Configurações de gameplay
     pub const MAX_TURNS: u32 = 50;
     pub const INITIAL_HAND_SIZE: usize = 5;
+    /// Máximo de inimigos em um encontro; reforços ocupam a vaga de quem caiu
+    pub const MAX_ENCOUNTER_ENEMIES: usize = 3;
     pub const MAX_BATTLE_LOG_LINES: usize = 8;
     pub const MAX_PLAYER_NAME_LENGTH: usize = 20;
+    pub const MAX_SEED_LENGTH: usize = 19;
+    pub const PLAYER_MAX_ENERGY: u32 = 3;
+    pub const DEFAULT_CARD_COST: u32 = 1;
+
+    // Configurações de save
+    pub const SAVE_SLOT_COUNT: usize = 4;
+    /// Checkpoints anteriores guardados ao lado de cada save
+    pub const SAVE_BACKUP_COUNT: usize = 3;
+    /// Pasta do jogo dentro dos dados do usuário
+    pub const SAVE_DIR_NAME: &str = "dani_seres_do_papel";
+    /// Save único de versões antigas, na pasta onde o jogo era aberto
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const LEGACY_SAVE_FILE: &str = "save_game.json";
     
     // Configurações de experiência
     pub const BASE_EXP_GAIN: u32 = 50;
     pub const EXP_MULTIPLIER_PER_LEVEL: u32 = 25;
     pub const HEALTH_INCREASE_PERCENT: f32 = 0.1;
+
+    // Configurações de ouro e da loja
+    pub const BASE_GOLD_GAIN: u32 = 20;
+    pub const GOLD_PER_ENEMY_LEVEL: u32 = 12;
+    pub const SHOP_OFFER_COUNT: usize = 6;
+    pub const SHOP_PRICE_COMMON: u32 = 50;
+    pub const SHOP_PRICE_UNCOMMON: u32 = 80;
+    pub const SHOP_PRICE_RARE: u32 = 140;
+    pub const SHOP_PRICE_EPIC: u32 = 220;
+    pub const SHOP_REMOVE_COST: u32 = 75;
+    pub const SHOP_UPGRADE_COST: u32 = 100;
+
+    // Configurações de recompensas
+    pub const CARD_REWARD_CHOICES: usize = 3;
+    pub const REWARD_WEIGHT_COMMON: f32 = 60.0;
+    pub const REWARD_WEIGHT_UNCOMMON: f32 = 30.0;
+    pub const REWARD_WEIGHT_RARE: f32 = 9.0;
+    pub const REWARD_WEIGHT_EPIC: f32 = 1.0;
+    /// Peso extra por nível do inimigo e por vitória anterior, aplicado às raridades melhores
+    pub const REWARD_RARITY_BONUS: f32 = 3.0;
+
+    // Configurações da run
+    /// Andares antes do chefe
+    pub const RUN_FLOORS: usize = 8;
+    pub const RUN_MIN_NODES_PER_FLOOR: usize = 2;
+    pub const RUN_MAX_NODES_PER_FLOOR: usize = 4;
+    /// Chance de uma parada ter um segundo caminho para o andar seguinte
+    pub const RUN_BRANCH_CHANCE: f64 = 0.4;
+    /// Aumento dos atributos dos inimigos por andar
+    pub const RUN_FLOOR_SCALING: f32 = 0.08;
+    pub const RUN_ELITE_MULTIPLIER: f32 = 1.35;
+    /// Vitórias extras contadas no sorteio de recompensa de uma elite
+    pub const RUN_ELITE_REWARD_BONUS: u32 = 2;
+    pub const RUN_REST_HEAL_PERCENT: f32 = 0.3;
+    pub const RUN_EVENT_HEAL_PERCENT: f32 = 0.25;
+    pub const RUN_EVENT_MAX_HEALTH: u32 = 8;
+    pub const RUN_EVENT_DAMAGE_PERCENT: f32 = 0.1;
     
     // Configurações de cartas
     pub const DECK_SIZE: usize = 40;
+    pub const MIN_DECK_SIZE: u32 = 20;
+    pub const MAX_DECK_SIZE: u32 = 40;
+    pub const MAX_COPIES_COMMON: u32 = 12;
+    pub const MAX_COPIES_UNCOMMON: u32 = 6;
+    pub const MAX_COPIES_RARE: u32 = 3;
+    pub const MAX_COPIES_EPIC: u32 = 1;
     pub const STATUS_EFFECT_DURATION_POISON: u32 = 4;
     pub const STATUS_EFFECT_DURATION_BURN: u32 = 3;
+    /// Duração dos bônus de carta, em turnos de quem usou a carta
+    pub const ATTACK_BUFF_DURATION: u32 = 3;
+    pub const DEFENSE_BUFF_DURATION: u32 = 2;
+    /// Cura extra de uma carta de cura melhorada, em fração da vida máxima
+    pub const UPGRADE_HEAL_BONUS: f32 = 0.1;
+    /// Turnos extras dos efeitos de status de uma carta melhorada
+    pub const UPGRADE_STATUS_DURATION_BONUS: u32 = 1;
     
     // UI Constants
     pub const CARD_WIDTH: f32 = 120.0;
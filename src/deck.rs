@@ -2,10 +2,14 @@ use macroquad::audio::{load_sound, play_sound, stop_sound, PlaySoundParams, Soun
 use macroquad::prelude::*;
 use ::rand::prelude::*;
 use ::rand::Rng;
+use crate::card_catalog::{CardCatalog, Rarity};
 use crate::config::config::*;
+use crate::effects::StatusEffect;
+use crate::enemy_roster::EnemyRoster;
+use serde::{Deserialize, Serialize};
 
 /// Tipos de carta disponíveis no jogo
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CardType {
     /// Ataque básico com dano especificado
     AttackBasic(u32),
@@ -19,65 +23,99 @@ pub enum CardType {
     Burn(u32),
     /// Carta de cura que recupera uma porcentagem da vida máxima
     Heal(f32),
+    /// Golpe único com o dano indicado, reduzido pela defesa do alvo
+    Strike(u32),
+    /// Golpe que ignora a defesa do alvo
+    Pierce(u32),
+    /// Vários golpes seguidos: dano de cada golpe e quantidade de golpes
+    MultiHit(u32, u32),
+    /// Escudo que absorve dano até o próximo turno de quem usou
+    Block(u32),
+    /// Aplica um efeito de status pela duração indicada; o alvo vem da definição do efeito
+    ApplyStatus(StatusEffect, u32),
+}
+
+impl CardType {
+    /// Cartas de bônus, status e cura são seguidas pelo ataque de quem as usou;
+    /// cartas de dano direto e de escudo resolvem tudo sozinhas
+    pub fn triggers_strike(&self) -> bool {
+        !matches!(
+            self,
+            CardType::Strike(_) | CardType::Pierce(_) | CardType::MultiHit(_, _) | CardType::Block(_)
+        )
+    }
 }
 
 /// Carta individual
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Card {
+    pub id: String,
     pub name: String,
     pub card_type: CardType,
     pub image_path: String,
+    #[serde(default)]
+    pub rarity: Rarity,
+    /// Energia gasta pelo jogador para usar a carta
+    #[serde(default = "default_card_cost")]
+    pub cost: u32,
+    /// Cartas exiladas saem da batalha depois de usadas em vez de irem para o descarte
+    #[serde(default)]
+    pub exhaust: bool,
+    /// Melhorada na loja: valores maiores e um + no nome
+    #[serde(default)]
+    pub upgraded: bool,
+}
+
+pub fn default_card_cost() -> u32 {
+    DEFAULT_CARD_COST
 }
 
 impl Card {
-    pub fn new(name: &str, card_type: CardType, image_path: &str) -> Self {
-        Self {
-            name: name.to_string(),
-            card_type,
-            image_path: image_path.to_string(),
+    /// Versão melhorada da carta: metade a mais de dano, defesa ou escudo, mais cura,
+    /// ou mais turnos de efeito. O custo não muda, então nenhuma carta de dano sai de graça.
+    pub fn upgrade(&mut self) {
+        if self.upgraded {
+            return;
         }
+        let boost = |amount: u32| amount + amount.div_ceil(2).max(1);
+        self.card_type = match self.card_type.clone() {
+            CardType::AttackBasic(amount) => CardType::AttackBasic(boost(amount)),
+            CardType::AttackStrong(amount) => CardType::AttackStrong(boost(amount)),
+            CardType::Defense(amount) => CardType::Defense(boost(amount)),
+            CardType::Strike(damage) => CardType::Strike(boost(damage)),
+            CardType::Pierce(damage) => CardType::Pierce(boost(damage)),
+            CardType::MultiHit(damage, times) => CardType::MultiHit(boost(damage), times),
+            CardType::Block(amount) => CardType::Block(boost(amount)),
+            CardType::Heal(percent) => CardType::Heal(percent + UPGRADE_HEAL_BONUS),
+            CardType::ApplyStatus(effect, duration) => CardType::ApplyStatus(effect, duration + UPGRADE_STATUS_DURATION_BONUS),
+            // A duração de veneno e queimadura vem da configuração; o motor soma o bônus das melhoradas
+            card_type @ (CardType::Poison(_) | CardType::Burn(_)) => card_type,
+        };
+        self.name.push('+');
+        self.upgraded = true;
     }
 
-
     pub async fn load_texture(&self) -> Result<Texture2D, macroquad::Error> {
         load_texture(&self.image_path).await
     }
 }
 
 /// Baralho do jogador
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Deck {
     pub cards: Vec<Card>,
 }
 
 impl Deck {
-    pub fn new() -> Self {
-        let mut cards = vec![
-            // Cards básicos (mais frequentes)
-            Card::new("Ataque", CardType::AttackBasic(12), "assets/cards/attack_basic.png"),
-            Card::new("Ataque", CardType::AttackBasic(12), "assets/cards/attack_basic.png"),
-            Card::new("Ataque", CardType::AttackBasic(12), "assets/cards/attack_basic.png"),
-            Card::new("Defesa", CardType::Defense(10), "assets/cards/defense.png"),
-            Card::new("Defesa", CardType::Defense(10), "assets/cards/defense.png"),
-            Card::new("Defesa", CardType::Defense(10), "assets/cards/defense.png"),
-            
-            // Cards especiais (menos frequentes)
-            Card::new("Ataque Forte", CardType::AttackStrong(20), "assets/cards/attack_strong.png"),
-            Card::new("Ataque Forte", CardType::AttackStrong(20), "assets/cards/attack_strong.png"),
-            Card::new("Veneno", CardType::Poison(5), "assets/cards/poison.png"),
-            Card::new("Veneno", CardType::Poison(5), "assets/cards/poison.png"),
-            Card::new("Cura", CardType::Heal(0.25), "assets/cards/heal.png"),
-            Card::new("Queimadura", CardType::Burn(10), "assets/cards/burn.png"),
-        ];
-        
-        // Preenche o deck até o tamanho desejado ciclando pelas cartas
-        cards = cards.into_iter().cycle().take(DECK_SIZE).collect();
+    pub fn new(catalog: &CardCatalog) -> Self {
+        // Preenche o deck até o tamanho desejado ciclando pelas cartas iniciais
+        let cards = catalog.starter_cards().into_iter().cycle().take(DECK_SIZE).collect();
         Self { cards }
     }
 
 
-    pub fn shuffle(&mut self) {
-        let mut rng = thread_rng();
-        self.cards.shuffle(&mut rng);
+    pub fn shuffle<R: Rng>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
     }
 
     pub fn draw(&mut self) -> Option<Card> {
@@ -85,8 +123,52 @@ impl Deck {
     }
 }
 
+/// Pilhas de cartas do jogador durante a batalha
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CardPiles {
+    pub draw: Deck,
+    pub discard: Vec<Card>,
+    pub exhaust: Vec<Card>,
+}
+
+impl CardPiles {
+    pub fn new(draw: Deck) -> Self {
+        Self {
+            draw,
+            discard: Vec::new(),
+            exhaust: Vec::new(),
+        }
+    }
+
+    /// Compra uma carta. Se a pilha de compra acabou, o descarte é embaralhado de volta
+    /// nela; o segundo valor indica quantas cartas foram reembaralhadas.
+    pub fn draw<R: Rng>(&mut self, rng: &mut R) -> (Option<Card>, usize) {
+        let mut reshuffled = 0;
+        if self.draw.cards.is_empty() && !self.discard.is_empty() {
+            reshuffled = self.discard.len();
+            self.draw.cards.append(&mut self.discard);
+            self.draw.shuffle(rng);
+        }
+        (self.draw.draw(), reshuffled)
+    }
+
+    /// Guarda uma carta usada no descarte ou no exílio
+    pub fn put_away(&mut self, card: Card) {
+        if card.exhaust {
+            self.exhaust.push(card);
+        } else {
+            self.discard.push(card);
+        }
+    }
+
+    /// Indica se ainda há cartas para comprar, contando o descarte
+    pub fn has_cards(&self) -> bool {
+        !self.draw.cards.is_empty() || !self.discard.is_empty()
+    }
+}
+
 /// Mão do jogador
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hand {
     pub cards: Vec<Card>,
 }
@@ -103,64 +185,14 @@ impl Hand {
     }
 
 
-    pub fn new_with_enemy_chances(enemy_name: &str, quantity: usize) -> Self {
-        let mut hand = Self { cards: vec![] };
-        let chances = EnemyCardChances::for_enemy(enemy_name);
-        let mut rng = thread_rng();
-        
+    /// Compra cartas sorteadas pela tabela de chances do inimigo
+    pub fn draw_with_chances<R: Rng>(&mut self, pool: &CardPool, quantity: usize, rng: &mut R) {
         for _ in 0..quantity {
             let roll: f32 = rng.r#gen();
-            let card = hand.select_card_by_chance(roll, &chances);
-            hand.cards.push(card);
-        }
-        
-        hand
-    }
-
-
-    fn select_card_by_chance(&self, roll: f32, chances: &EnemyCardChances) -> Card {
-        let card_options = [
-            (
-                chances.attack_basic,
-                "Ataque Básico",
-                CardType::AttackBasic(12),
-                "assets/cards/attack.png",
-            ),
-            (
-                chances.attack_strong,
-                "Ataque Forte",
-                CardType::AttackStrong(20),
-                "assets/cards/attack.png",
-            ),
-            (
-                chances.defense,
-                "Defesa",
-                CardType::Defense(10),
-                "assets/cards/defense.png",
-            ),
-            (
-                chances.poison,
-                "Veneno",
-                CardType::Poison(5),
-                "assets/cards/poison.png",
-            ),
-            (
-                chances.burn,
-                "Queimadura",
-                CardType::Burn(10),
-                "assets/cards/burn.png",
-            ),
-        ];
-    
-        let mut cumulative = 0.0;
-        for (chance, name, card_type, asset) in card_options {
-            cumulative += chance;
-            if roll < cumulative {
-                return Card::new(name, card_type, asset);
+            if let Some(card) = pool.select(roll) {
+                self.cards.push(card);
             }
         }
-    
-        Card::new("Cura", CardType::Heal(0.25), "assets/cards/heal.png")
     }
 
     pub fn display(&self) {
@@ -190,71 +222,57 @@ impl Hand {
     }
 }
 
-/// Estrutura para definir chances de cartas por inimigo
+/// Cartas que um inimigo pode comprar, cada uma com sua chance de sair
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardPool {
+    pub entries: Vec<(Card, f32)>,
+}
+
+impl CardPool {
+    /// Escolhe a carta cuja faixa acumulada contém `roll`; a última cobre o que sobrar
+    pub fn select(&self, roll: f32) -> Option<Card> {
+        let mut cumulative = 0.0;
+        for (card, chance) in &self.entries {
+            cumulative += chance;
+            if roll < cumulative {
+                return Some(card.clone());
+            }
+        }
+        self.entries.last().map(|(card, _)| card.clone())
+    }
+}
+
+/// Chance de um inimigo comprar uma carta do catálogo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardChance {
+    pub card: String,
+    pub chance: f32,
+}
+
+/// Tabela de chances de cartas de um inimigo, definida no roster de inimigos.
+/// A ordem importa: a última carta cobre a probabilidade que sobrar.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct EnemyCardChances {
-    pub attack_basic: f32,
-    pub attack_strong: f32,
-    pub defense: f32,
-    pub poison: f32,
-    pub burn: f32,
-    pub heal: f32,
+    pub entries: Vec<CardChance>,
 }
 
 impl EnemyCardChances {
-
-    pub fn for_enemy(enemy_name: &str) -> Self {
-        match enemy_name.to_lowercase().as_str() {
-            "dragão" | "dragon" => Self {
-                attack_basic: 0.10,  
-                attack_strong: 0.05,
-                defense: 0.10,
-                poison: 0.10,
-                burn: 0.50,
-                heal: 0.05,   
-            },
-            "devorador" | "devourer" => Self {
-                attack_basic: 0.30,
-                attack_strong: 0.35,
-                defense: 0.05,
-                poison: 0.10,
-                burn: 0.10,
-                heal: 0.10,
-            },
-            "zumbi" | "zombie" => Self {
-                attack_basic: 0.20,
-                attack_strong: 0.15,
-                defense: 0.10,
-                poison: 0.35,
-                burn: 0.10,
-                heal: 0.10,
-            },
-            "esqueleto" | "skeleton" => Self {
-                attack_basic: 0.25,
-                attack_strong: 0.20,
-                defense: 0.15,
-                poison: 0.15,
-                burn: 0.15,
-                heal: 0.10,
-            },
-            _ => Self {
-                attack_basic: 0.10,
-                attack_strong: 0.05,
-                defense: 0.10,
-                poison: 0.10,
-                burn: 0.50,
-                heal: 0.05,
-            },
+    /// Monta a tabela de sorteio com as cartas do catálogo
+    pub fn card_pool(&self, catalog: &CardCatalog) -> CardPool {
+        CardPool {
+            entries: self
+                .entries
+                .iter()
+                .filter_map(|entry| catalog.card(&entry.card).map(|card| (card, entry.chance)))
+                .collect(),
         }
     }
 }
 
 pub struct CardTextureManager {
-    pub attack_basic_texture: Option<Texture2D>,
-    pub attack_strong_texture: Option<Texture2D>,
-    pub defense_texture: Option<Texture2D>,
-    pub poison_texture: Option<Texture2D>,
-    pub heal_texture: Option<Texture2D>,
-    pub burn_texture: Option<Texture2D>,
+    /// Artes das cartas, indexadas pelo caminho da imagem
+    pub card_textures: std::collections::HashMap<String, Texture2D>,
     pub card_back_texture: Option<Texture2D>,
     pub enemy_textures: std::collections::HashMap<String, Texture2D>,
     pub background_texture: Option<Texture2D>,
@@ -269,12 +287,7 @@ pub struct CardTextureManager {
 impl CardTextureManager {
     pub fn new() -> Self {
         Self {
-            attack_basic_texture: None,
-            attack_strong_texture: None,
-            defense_texture: None,
-            poison_texture: None,
-            heal_texture: None,
-            burn_texture: None,
+            card_textures: std::collections::HashMap::new(),
             card_back_texture: None,
             enemy_textures: std::collections::HashMap::new(),
             background_texture: None,
@@ -287,29 +300,36 @@ impl CardTextureManager {
     }
 
 
-    pub async fn load_all_textures(&mut self) {
-        self.attack_basic_texture = load_texture("assets/cards/attack_basic.png").await.ok();
-        self.attack_strong_texture = load_texture("assets/cards/attack_strong.png").await.ok();
-        self.defense_texture = load_texture("assets/cards/defense.png").await.ok();
-        self.poison_texture = load_texture("assets/cards/poison.png").await.ok();
-        self.heal_texture = load_texture("assets/cards/heal.png").await.ok();
+    pub async fn load_all_textures(&mut self, catalog: &CardCatalog, roster: &EnemyRoster) {
+        for definition in &catalog.cards {
+            self.load_card_texture(&definition.image).await;
+        }
         self.card_back_texture = load_texture("assets/cards/card_back.png").await.ok();
         self.background_texture = load_texture("assets/background.png").await.ok();
-        self.burn_texture = load_texture("assets/cards/burn.png").await.ok();
-        self.load_enemy_texture("assets/enemies/skeleton.png").await;
-        self.load_enemy_texture("assets/enemies/zombie.png").await;
-        self.load_enemy_texture("assets/enemies/dragon.png").await;
-        self.load_enemy_texture("assets/enemies/devourer.png").await;
+        for enemy in &roster.enemies {
+            self.load_enemy_texture(&enemy.image).await;
+        }
 
         // Carregar áudios (silenciosamente falha se arquivos não existem)
         self.background_music = load_sound("assets/audio/music/background.ogg").await.ok();
         self.card_use_sound = load_sound("assets/audio/sfx/card_use.wav").await.ok();
         self.enemy_attack_basic_sound = load_sound("assets/audio/sfx/enemy_attack_basic.wav").await.ok();
         
-        self.load_enemy_sound("skeleton", "assets/audio/sfx/skeleton_attack.wav").await;
-        self.load_enemy_sound("zombie", "assets/audio/sfx/zombie_attack.wav").await;
-        self.load_enemy_sound("dragon", "assets/audio/sfx/dragon_attack.wav").await;
-        self.load_enemy_sound("devourer", "assets/audio/sfx/devourer_attack.wav").await;
+        for enemy in &roster.enemies {
+            if let Some(sound_path) = &enemy.attack_sound {
+                self.load_enemy_sound(sound_path).await;
+            }
+        }
+    }
+
+
+    pub async fn load_card_texture(&mut self, image_path: &str) {
+        if self.card_textures.contains_key(image_path) {
+            return;
+        }
+        if let Ok(texture) = load_texture(image_path).await {
+            self.card_textures.insert(image_path.to_string(), texture);
+        }
     }
 
 
@@ -320,22 +340,15 @@ impl CardTextureManager {
     }
 
 
-    pub async fn load_enemy_sound(&mut self, enemy_name: &str, sound_path: &str) {
+    pub async fn load_enemy_sound(&mut self, sound_path: &str) {
         if let Ok(sound) = load_sound(sound_path).await {
-            self.enemy_sounds.insert(enemy_name.to_string(), sound);
+            self.enemy_sounds.insert(sound_path.to_string(), sound);
         }
     }
 
 
     pub fn get_texture_for_card(&self, card: &Card) -> Option<&Texture2D> {
-        match card.card_type {
-                    CardType::AttackBasic(_) => self.attack_basic_texture.as_ref(),
-        CardType::AttackStrong(_) => self.attack_strong_texture.as_ref(),
-            CardType::Defense(_) => self.defense_texture.as_ref(),
-            CardType::Poison(_) => self.poison_texture.as_ref(),
-            CardType::Heal(_) => self.heal_texture.as_ref(),
-            CardType::Burn(_) => self.burn_texture.as_ref(),
-        }
+        self.card_textures.get(&card.image_path)
     }
 
 
@@ -353,13 +366,18 @@ impl CardTextureManager {
             );
         } else {
             // Fallback: desenha um retângulo colorido se a imagem não carregar
-            let color = match card.card_type {
-                        CardType::AttackBasic(_) => RED,
-        CardType::AttackStrong(_) => RED,
-                CardType::Defense(_) => BLUE,
-                CardType::Poison(_) => GREEN,
-                CardType::Heal(_) => YELLOW,
-                CardType::Burn(_) => RED,
+            let (color, value) = match card.card_type {
+                CardType::AttackBasic(amount) => (RED, format!("+{} ATK", amount)),
+                CardType::AttackStrong(amount) => (RED, format!("+{} ATK", amount)),
+                CardType::Defense(amount) => (BLUE, format!("+{} DEF", amount)),
+                CardType::Poison(_) => (GREEN, "Poison".to_string()),
+                CardType::Heal(percent) => (YELLOW, format!("+{:.0}% HP", percent * 100.0)),
+                CardType::Burn(_) => (RED, "Burn".to_string()),
+                CardType::Strike(amount) => (ORANGE, format!("{} DMG", amount)),
+                CardType::Pierce(amount) => (PURPLE, format!("{} DMG, no DEF", amount)),
+                CardType::MultiHit(amount, times) => (MAGENTA, format!("{} x{} DMG", amount, times)),
+                CardType::Block(amount) => (SKYBLUE, format!("{} Block", amount)),
+                CardType::ApplyStatus(effect, duration) => (VIOLET, format!("{:?} {}", effect, duration)),
             };
             draw_rectangle(x, y, width, height, color);
 
@@ -368,6 +386,37 @@ impl CardTextureManager {
                 x + width / 2.0 - measure_text(&card.name, None, text_size as u16, 1.0).width / 2.0;
             let text_y = y + height / 2.0;
             draw_text(&card.name, text_x, text_y, text_size, BLACK);
+
+            let value_size = 14.0;
+            let value_x =
+                x + width / 2.0 - measure_text(&value, None, value_size as u16, 1.0).width / 2.0;
+            draw_text(&value, value_x, text_y + 20.0, value_size, BLACK);
+        }
+
+        // Custo de energia no canto superior esquerdo
+        let radius = (width * 0.12).max(8.0);
+        let center_x = x + radius + 4.0;
+        let center_y = y + radius + 4.0;
+        draw_circle(center_x, center_y, radius, Color::new(0.1, 0.3, 0.8, 0.95));
+        draw_circle_lines(center_x, center_y, radius, 2.0, WHITE);
+        let cost_text = card.cost.to_string();
+        let cost_size = radius * 1.6;
+        let cost_dims = measure_text(&cost_text, None, cost_size as u16, 1.0);
+        draw_text(
+            &cost_text,
+            center_x - cost_dims.width / 2.0,
+            center_y + cost_dims.offset_y / 2.0,
+            cost_size,
+            WHITE,
+        );
+
+        // Marca de carta melhorada no canto superior direito
+        if card.upgraded {
+            let badge_x = x + width - radius - 4.0;
+            draw_circle(badge_x, center_y, radius, Color::new(0.1, 0.6, 0.2, 0.95));
+            draw_circle_lines(badge_x, center_y, radius, 2.0, WHITE);
+            let plus_dims = measure_text("+", None, cost_size as u16, 1.0);
+            draw_text("+", badge_x - plus_dims.width / 2.0, center_y + plus_dims.offset_y / 2.0, cost_size, WHITE);
         }
     }
 
@@ -502,8 +551,8 @@ impl CardTextureManager {
     }
 
 
-    pub fn play_enemy_sound(&self, enemy_name: &str) {
-        if let Some(sound) = self.enemy_sounds.get(enemy_name) {
+    pub fn play_enemy_sound(&self, sound_path: Option<&str>) {
+        if let Some(sound) = sound_path.and_then(|path| self.enemy_sounds.get(path)) {
             play_sound(
                 sound,
                 PlaySoundParams {
@@ -1,5 +1,52 @@
-#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum StatusEffect {
     Poison,
     Burn,
+    Weak,
+    Vulnerable,
+    Stun,
+    Regeneration,
+    Thorns,
+}
+
+/// Efeito de status ativo em uma entidade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveStatus {
+    /// Disparos que ainda restam
+    pub duration: u32,
+    /// Intensidade, para efeitos que acumulam
+    pub stacks: u32,
+}
+
+/// Como uma nova aplicação se combina com o efeito já ativo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Stacking {
+    /// Soma as durações
+    Duration,
+    /// Soma uma camada de intensidade e mantém a maior duração
+    Intensity,
+    /// Só renova a duração, sem acumular
+    Refresh,
+}
+
+impl Stacking {
+    pub fn combine(self, active: &mut ActiveStatus, duration: u32) {
+        match self {
+            Stacking::Duration => active.duration += duration,
+            Stacking::Intensity => {
+                active.stacks += 1;
+                active.duration = active.duration.max(duration);
+            }
+            Stacking::Refresh => active.duration = active.duration.max(duration),
+        }
+    }
+}
+
+/// Momento do turno do afetado em que o efeito dispara e perde duração
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusTrigger {
+    TurnStart,
+    TurnEnd,
 }
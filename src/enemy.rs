@@ -1,8 +1,14 @@
-use crate::deck::{Card, Deck, Hand};
-use crate::effects::StatusEffect;
+use crate::boss::BossPhase;
+use crate::deck::{Card, CardPool, Hand};
+use ::rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::effects::{ActiveStatus, StatusEffect};
+use crate::enemy_ai::EnemyAiKind;
 use crate::entity::Entity;
-use std::collections::HashMap;
+use crate::modifiers::Modifiers;
+use std::collections::BTreeMap;
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Enemy {
     pub name: String,
     pub hand: Hand,
@@ -10,8 +16,24 @@ pub struct Enemy {
     pub max_health: u32,
     pub attack: u32,
     pub defense: u32,
-    pub status_effects: HashMap<StatusEffect, u32>,
+    pub status_effects: BTreeMap<StatusEffect, ActiveStatus>,
     pub image_path: String,
+    pub card_pool: CardPool,
+    pub attack_sound: Option<String>,
+    #[serde(default)]
+    pub modifiers: Modifiers,
+    /// Estratégia usada para escolher as jogadas
+    #[serde(default)]
+    pub ai: EnemyAiKind,
+    /// Carta que o inimigo anunciou para o próximo turno
+    #[serde(default)]
+    pub intent: Option<Card>,
+    /// Fases de chefe, na ordem em que são atingidas
+    #[serde(default)]
+    pub phases: Vec<BossPhase>,
+    /// Quantas fases já começaram nesta batalha
+    #[serde(default)]
+    pub phase: usize,
 }
 
 impl Enemy {
@@ -21,79 +43,87 @@ impl Enemy {
         max_health: u32,
         attack: u32,
         defense: u32,
-        deck: &mut Deck,
         image_path: &str,
+        card_pool: CardPool,
     ) -> Self {
-        deck.shuffle();
         Self {
             name: name.to_string(),
-            hand: Hand::new_with_enemy_chances(name, 5),
+            hand: Hand::new(),
             health: health,
             max_health: max_health,
             attack: attack,
             defense: defense,
-            status_effects: HashMap::new(),
+            status_effects: BTreeMap::new(),
             image_path: image_path.to_string(),
+            card_pool,
+            attack_sound: None,
+            modifiers: Modifiers::default(),
+            ai: EnemyAiKind::default(),
+            intent: None,
+            phases: Vec::new(),
+            phase: 0,
         }
     }
+
+    pub fn intent_card(&self) -> Option<&Card> {
+        self.intent.as_ref()
+    }
+
+    /// Joga a carta anunciada; se ela veio da mão, compra outra pela tabela de chances
+    pub fn play_card<R: Rng>(&mut self, rng: &mut R) -> Option<Card> {
+        let card = self.intent.take()?;
+        if let Some(index) = self.hand.cards.iter().position(|held| held.id == card.id) {
+            self.hand.discard(index);
+            self.hand.draw_with_chances(&self.card_pool, 1, rng);
+        }
+        Some(card)
+    }
 }
 
 impl Entity for Enemy {
-    fn discart_card(&mut self, hand: &mut Hand, index: usize) -> Option<Card> {
-        hand.discard(index)
+    fn health(&self) -> u32 {
+        self.health
     }
 
-    fn damage(&mut self, damage: u32) {
-        let damage_final = damage.saturating_sub(self.defense);
-        self.health = self.health.saturating_sub(damage_final);
+    fn max_health(&self) -> u32 {
+        self.max_health
     }
-    fn heal(&mut self, heal: u32) {
-        self.health = self.health.saturating_add(heal);
-        if self.health > self.max_health {
-            self.health = self.max_health;
-        }
+
+    fn base_attack(&self) -> u32 {
+        self.attack
     }
 
-    fn attack_up(&mut self, attack: u32) {
-        self.attack += attack;
+    fn base_defense(&self) -> u32 {
+        self.defense
     }
 
-    fn defense_up(&mut self, defense: u32) {
-        self.defense += defense;
+    fn modifiers(&self) -> &Modifiers {
+        &self.modifiers
     }
 
-    fn status_effect(&mut self, status_effect: StatusEffect, duration: u32) {
-        if let Some(existing_duration) = self.status_effects.get_mut(&status_effect) {
-            *existing_duration += duration;
-        } else {
-            self.status_effects.insert(status_effect, duration);
-        }
+    fn modifiers_mut(&mut self) -> &mut Modifiers {
+        &mut self.modifiers
     }
 
-    fn apply_status_effects(&mut self) {
-        let mut expired_effects = vec![];
+    fn status_effects(&self) -> &BTreeMap<StatusEffect, ActiveStatus> {
+        &self.status_effects
+    }
 
-        for (effect, duration) in self.status_effects.iter_mut() {
-            match effect {
-                StatusEffect::Poison => {
-                    println!("{} sofre 8 de dano por envenenamento!", self.name);
-                    self.health = self.health.saturating_sub(8);
-                }
-                StatusEffect::Burn => {
-                    println!("{} sofre 10 de dano por queimadura!", self.name);
-                    self.health = self.health.saturating_sub(10);
-                }
-            }
+    fn status_effects_mut(&mut self) -> &mut BTreeMap<StatusEffect, ActiveStatus> {
+        &mut self.status_effects
+    }
 
-            *duration = duration.saturating_sub(1);
-            if *duration == 0 {
-                expired_effects.push(*effect);
-            }
-        }
+    fn discart_card(&mut self, hand: &mut Hand, index: usize) -> Option<Card> {
+        hand.discard(index)
+    }
 
-        for effect in expired_effects {
-            println!("{:?} em {} terminou.", effect, self.name);
-            self.status_effects.remove(&effect);
+    fn set_health(&mut self, health: u32) {
+        self.health = health.min(self.max_health);
+    }
+    fn heal(&mut self, heal: u32) {
+        self.health = self.health.saturating_add(heal);
+        if self.health > self.max_health {
+            self.health = self.max_health;
         }
     }
 }
@@ -0,0 +1,252 @@
+use crate::deck::{Card, CardType};
+use crate::effects::StatusEffect;
+use crate::enemy::Enemy;
+use crate::entity::Entity;
+use crate::player::Player;
+use crate::status_registry::StatusRegistry;
+use ::rand::Rng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+/// O que a IA do inimigo enxerga ao decidir a próxima jogada
+pub struct AiContext<'a> {
+    pub enemy: &'a Enemy,
+    pub player: &'a Player,
+    pub statuses: &'a StatusRegistry,
+}
+
+/// Estratégia que escolhe a próxima carta do inimigo
+pub trait EnemyAi {
+    fn choose_card(&mut self, context: &AiContext, rng: &mut ChaCha12Rng) -> Option<Card>;
+
+    /// Quanto a estratégia já avançou, para continuar do mesmo ponto ao retomar um save; as sem memória ficam no zero
+    fn progress(&self) -> usize {
+        0
+    }
+}
+
+/// Estratégia de cada inimigo, definida no roster de inimigos
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum EnemyAiKind {
+    /// Carta aleatória da mão, que é comprada pela tabela de chances
+    #[default]
+    WeightedRandom,
+    /// Ciclo fixo de jogadas pelo id da carta; as cartas precisam estar na tabela de chances
+    Scripted(Vec<String>),
+    /// Pontua cada carta da mão pela situação atual da batalha
+    Utility,
+}
+
+impl EnemyAiKind {
+    pub fn build(&self) -> Box<dyn EnemyAi> {
+        self.resume(0)
+    }
+
+    /// Estratégia no ponto indicado por `EnemyAi::progress`
+    pub fn resume(&self, progress: usize) -> Box<dyn EnemyAi> {
+        match self {
+            EnemyAiKind::WeightedRandom => Box::new(WeightedRandomAi),
+            EnemyAiKind::Scripted(pattern) => Box::new(ScriptedAi { pattern: pattern.clone(), step: progress }),
+            EnemyAiKind::Utility => Box::new(UtilityAi),
+        }
+    }
+}
+
+pub struct WeightedRandomAi;
+
+impl EnemyAi for WeightedRandomAi {
+    fn choose_card(&mut self, context: &AiContext, rng: &mut ChaCha12Rng) -> Option<Card> {
+        let hand = &context.enemy.hand.cards;
+        if hand.is_empty() {
+            return None;
+        }
+        Some(hand[rng.gen_range(0..hand.len())].clone())
+    }
+}
+
+pub struct ScriptedAi {
+    pattern: Vec<String>,
+    step: usize,
+}
+
+impl EnemyAi for ScriptedAi {
+    fn choose_card(&mut self, context: &AiContext, rng: &mut ChaCha12Rng) -> Option<Card> {
+        if self.pattern.is_empty() {
+            return WeightedRandomAi.choose_card(context, rng);
+        }
+        let id = &self.pattern[self.step % self.pattern.len()];
+        self.step += 1;
+
+        let scripted = context.enemy.card_pool.entries.iter().find(|(card, _)| &card.id == id);
+        match scripted {
+            Some((card, _)) => Some(card.clone()),
+            None => WeightedRandomAi.choose_card(context, rng),
+        }
+    }
+
+    fn progress(&self) -> usize {
+        self.step
+    }
+}
+
+pub struct UtilityAi;
+
+impl UtilityAi {
+    /// Dano que um golpe causaria no jogador agora, depois da defesa e dos multiplicadores
+    fn expected_damage(context: &AiContext, damage: u32, pierce: bool) -> u32 {
+        let multiplier = context.statuses.damage_dealt_multiplier(context.enemy.status_effects())
+            * context.statuses.damage_taken_multiplier(context.player.status_effects());
+        let damage = (damage as f32 * multiplier) as u32;
+        let damage = if pierce { damage } else { damage.saturating_sub(context.player.defense()) };
+        damage.saturating_sub(context.player.modifiers().shield)
+    }
+
+    fn score(context: &AiContext, card: &Card) -> f32 {
+        let enemy = context.enemy;
+        let player = context.player;
+        let missing_health = 1.0 - enemy.health() as f32 / enemy.max_health().max(1) as f32;
+        let threat = player.attack().saturating_sub(enemy.defense()) as f32;
+
+        let mut attack = enemy.attack();
+        let mut score = match &card.card_type {
+            CardType::AttackBasic(amount) | CardType::AttackStrong(amount) => {
+                attack += amount;
+                0.0
+            }
+            CardType::Strike(damage) => Self::expected_damage(context, *damage, false) as f32,
+            CardType::Pierce(damage) => Self::expected_damage(context, *damage, true) as f32,
+            CardType::MultiHit(damage, times) => (Self::expected_damage(context, *damage, false) * times) as f32,
+            CardType::Defense(amount) | CardType::Block(amount) => {
+                *amount as f32 * (0.5 + missing_health) + threat * 0.5
+            }
+            CardType::Heal(percent) => enemy.max_health() as f32 * percent * missing_health * 2.0,
+            CardType::Poison(_) => Self::status_score(context, StatusEffect::Poison),
+            CardType::Burn(_) => Self::status_score(context, StatusEffect::Burn),
+            CardType::ApplyStatus(effect, _) => Self::status_score(context, *effect),
+        };
+
+        if card.card_type.triggers_strike() {
+            score += Self::expected_damage(context, attack, false) as f32;
+        }
+        // Golpe que derruba o jogador vale mais que qualquer outra coisa
+        let lethal = match &card.card_type {
+            CardType::Strike(_) | CardType::Pierce(_) | CardType::MultiHit(_, _) => score as u32 >= player.health(),
+            _ => card.card_type.triggers_strike() && Self::expected_damage(context, attack, false) >= player.health(),
+        };
+        if lethal {
+            score += 1000.0;
+        }
+        score
+    }
+
+    /// Efeitos repetidos valem pouco; os prejudiciais valem pelo dano ou pela penalidade que causam
+    fn status_score(context: &AiContext, effect: StatusEffect) -> f32 {
+        let Some(definition) = context.statuses.get(effect) else {
+            return 0.0;
+        };
+        let target = if definition.beneficial {
+            context.enemy.status_effects()
+        } else {
+            context.player.status_effects()
+        };
+        let already_active = target.contains_key(&effect);
+        let value = (definition.tick_damage + definition.tick_heal + definition.thorns) as f32 * 2.0
+            + (definition.damage_dealt_multiplier - 1.0).abs() * 40.0
+            + (definition.damage_taken_multiplier - 1.0).abs() * 40.0
+            + if definition.skips_turn { 50.0 } else { 0.0 };
+        if already_active { value * 0.25 } else { value }
+    }
+}
+
+impl EnemyAi for UtilityAi {
+    fn choose_card(&mut self, context: &AiContext, _rng: &mut ChaCha12Rng) -> Option<Card> {
+        context
+            .enemy
+            .hand
+            .cards
+            .iter()
+            .map(|card| (card, Self::score(context, card)))
+            .fold(None, |best: Option<(&Card, f32)>, (card, score)| match best {
+                Some((_, best_score)) if best_score >= score => best,
+                _ => Some((card, score)),
+            })
+            .map(|(card, _)| card.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::CardPool;
+    use ::rand::SeedableRng;
+
+    fn card(id: &str, card_type: CardType) -> Card {
+        Card {
+            id: id.to_string(),
+            name: id.to_string(),
+            card_type,
+            image_path: String::new(),
+            rarity: Default::default(),
+            cost: 1,
+            exhaust: false,
+            upgraded: false,
+        }
+    }
+
+    fn enemy(cards: Vec<Card>) -> Enemy {
+        let pool = CardPool { entries: cards.iter().map(|card| (card.clone(), 1.0 / cards.len() as f32)).collect() };
+        let mut enemy = Enemy::new("Dummy", 50, 50, 5, 0, "", pool);
+        enemy.hand.cards = cards;
+        enemy
+    }
+
+    #[test]
+    fn scripted_ai_cycles_through_the_pattern() {
+        let enemy = enemy(vec![card("jab", CardType::Strike(3)), card("guard", CardType::Block(5))]);
+        let player = Player::new("Tester");
+        let statuses = StatusRegistry::embedded();
+        let context = AiContext { enemy: &enemy, player: &player, statuses: &statuses };
+        let mut rng = ChaCha12Rng::seed_from_u64(1);
+
+        let mut ai = EnemyAiKind::Scripted(vec!["guard".into(), "jab".into(), "jab".into()]).build();
+        let plays: Vec<String> = (0..4).map(|_| ai.choose_card(&context, &mut rng).unwrap().id).collect();
+        assert_eq!(plays, ["guard", "jab", "jab", "guard"]);
+    }
+
+    #[test]
+    fn weighted_random_ai_plays_from_the_hand() {
+        let enemy = enemy(vec![card("jab", CardType::Strike(3)), card("guard", CardType::Block(5))]);
+        let player = Player::new("Tester");
+        let statuses = StatusRegistry::embedded();
+        let context = AiContext { enemy: &enemy, player: &player, statuses: &statuses };
+        let mut rng = ChaCha12Rng::seed_from_u64(1);
+
+        for _ in 0..20 {
+            let choice = WeightedRandomAi.choose_card(&context, &mut rng).unwrap();
+            assert!(enemy.hand.cards.iter().any(|held| held.id == choice.id));
+        }
+        let empty = Enemy::new("Empty", 10, 10, 1, 0, "", CardPool::default());
+        let context = AiContext { enemy: &empty, player: &player, statuses: &statuses };
+        assert!(WeightedRandomAi.choose_card(&context, &mut rng).is_none());
+    }
+
+    #[test]
+    fn utility_ai_goes_for_the_kill_and_guards_when_hurt() {
+        let cards = vec![card("jab", CardType::Pierce(30)), card("guard", CardType::Block(20))];
+        let statuses = StatusRegistry::embedded();
+        let mut rng = ChaCha12Rng::seed_from_u64(1);
+
+        let mut weak_player = Player::new("Tester");
+        weak_player.health = 20;
+        let healthy = enemy(cards.clone());
+        let context = AiContext { enemy: &healthy, player: &weak_player, statuses: &statuses };
+        assert_eq!(UtilityAi.choose_card(&context, &mut rng).unwrap().id, "jab");
+
+        let mut strong_player = Player::new("Tester");
+        strong_player.attack = 60;
+        let mut hurt = enemy(vec![card("jab", CardType::Pierce(1)), card("guard", CardType::Block(20))]);
+        hurt.health = 5;
+        let context = AiContext { enemy: &hurt, player: &strong_player, statuses: &statuses };
+        assert_eq!(UtilityAi.choose_card(&context, &mut rng).unwrap().id, "guard");
+    }
+}
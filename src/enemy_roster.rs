@@ -0,0 +1,102 @@
+use crate::boss::BossPhaseDefinition;
+use crate::card_catalog::CardCatalog;
+use crate::deck::EnemyCardChances;
+use crate::enemy::Enemy;
+use crate::enemy_ai::EnemyAiKind;
+use crate::error::{GameError, GameResult};
+use macroquad::file::load_string;
+use serde::{Deserialize, Serialize};
+
+fn default_level() -> u32 {
+    1
+}
+
+/// Definição de um inimigo no arquivo de dados
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyDefinition {
+    pub id: u32,
+    pub name: String,
+    pub base_health: u32,
+    pub base_attack: u32,
+    pub base_defense: u32,
+    /// Valores do primeiro encontro; quando ausentes, usam os valores base
+    #[serde(default)]
+    pub health: Option<u32>,
+    #[serde(default)]
+    pub attack: Option<u32>,
+    #[serde(default)]
+    pub defense: Option<u32>,
+    #[serde(default = "default_level")]
+    pub level: u32,
+    pub emoji: Option<String>,
+    pub image: String,
+    pub attack_sound: Option<String>,
+    /// Posição na cadeia de desbloqueio; o menor começa liberado
+    pub unlock_order: u32,
+    pub card_chances: EnemyCardChances,
+    #[serde(default)]
+    pub ai: EnemyAiKind,
+    /// Fases de chefe; a maioria dos inimigos não tem nenhuma
+    #[serde(default)]
+    pub phases: Vec<BossPhaseDefinition>,
+    /// Ids dos inimigos que entram na luta junto com este
+    #[serde(default)]
+    pub escort: Vec<u32>,
+}
+
+impl EnemyDefinition {
+    /// Inimigo com os valores do primeiro encontro, usado como escolta ou reforço
+    pub fn to_enemy(&self, catalog: &CardCatalog) -> Enemy {
+        let health = self.health.unwrap_or(self.base_health);
+        let mut enemy = Enemy::new(
+            &self.name,
+            health,
+            health,
+            self.attack.unwrap_or(self.base_attack),
+            self.defense.unwrap_or(self.base_defense),
+            &self.image,
+            self.card_chances.card_pool(catalog),
+        );
+        enemy.attack_sound = self.attack_sound.clone();
+        enemy.ai = self.ai.clone();
+        enemy
+    }
+}
+
+/// Lista de inimigos do jogo, carregada de `assets/data/enemies.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyRoster {
+    pub enemies: Vec<EnemyDefinition>,
+}
+
+impl EnemyRoster {
+    pub const PATH: &'static str = "assets/data/enemies.json";
+
+    /// Lê o roster e ordena os inimigos pela ordem de desbloqueio
+    pub fn from_json(json: &str) -> GameResult<Self> {
+        let mut roster: Self = serde_json::from_str(json)
+            .map_err(|e| GameError::AssetLoadError(format!("{}: {}", Self::PATH, e)))?;
+        roster.enemies.sort_by_key(|enemy| enemy.unlock_order);
+        Ok(roster)
+    }
+
+    /// Cópia do roster embutida no executável, usada se o arquivo não puder ser lido
+    pub fn embedded() -> Self {
+        Self::from_json(include_str!("../assets/data/enemies.json"))
+            .expect("embedded enemy roster must be valid")
+    }
+
+    pub fn get(&self, id: u32) -> Option<&EnemyDefinition> {
+        self.enemies.iter().find(|enemy| enemy.id == id)
+    }
+
+    pub async fn load() -> Self {
+        match load_string(Self::PATH).await {
+            Ok(json) => Self::from_json(&json).unwrap_or_else(|e| {
+                eprintln!("⚠️ {}, using built-in enemies", e);
+                Self::embedded()
+            }),
+            Err(_) => Self::embedded(),
+        }
+    }
+}
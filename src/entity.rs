@@ -1,12 +1,121 @@
+use crate::config::config::*;
 use crate::deck::{Card, Hand};
-use crate::effects::StatusEffect;
+use crate::effects::{ActiveStatus, Stacking, StatusEffect, StatusTrigger};
+use crate::modifiers::{Buff, Modifiers, Stat};
+use crate::status_registry::StatusRegistry;
+use std::collections::BTreeMap;
+
+/// O que um efeito de status fez ao disparar
+pub struct StatusTickOutcome {
+    pub effect: StatusEffect,
+    pub damage: u32,
+    pub healed: u32,
+    pub expired: bool,
+}
 
 pub trait Entity {
+    fn health(&self) -> u32;
+    fn max_health(&self) -> u32;
+    /// Ataque e defesa sem bônus; só eles persistem entre batalhas
+    fn base_attack(&self) -> u32;
+    fn base_defense(&self) -> u32;
+    fn modifiers(&self) -> &Modifiers;
+    fn modifiers_mut(&mut self) -> &mut Modifiers;
+    fn status_effects(&self) -> &BTreeMap<StatusEffect, ActiveStatus>;
+    fn status_effects_mut(&mut self) -> &mut BTreeMap<StatusEffect, ActiveStatus>;
+    fn set_health(&mut self, health: u32);
     fn discart_card(&mut self, hand: &mut Hand, index: usize) -> Option<Card>;
-    fn damage(&mut self, damage: u32);
-    fn defense_up(&mut self, defense: u32);
-    fn attack_up(&mut self, attack: u32);
     fn heal(&mut self, heal: u32);
-    fn status_effect(&mut self, status_effect: StatusEffect, duration: u32);
-    fn apply_status_effects(&mut self);
+
+    /// Ataque efetivo: base mais os bônus ativos
+    fn attack(&self) -> u32 {
+        self.base_attack() + self.modifiers().total(Stat::Attack)
+    }
+
+    /// Defesa efetiva: base mais os bônus ativos
+    fn defense(&self) -> u32 {
+        self.base_defense() + self.modifiers().total(Stat::Defense)
+    }
+
+    /// Dano reduzido pela defesa e depois pelo escudo; devolve quanto o escudo absorveu
+    fn damage(&mut self, damage: u32) -> u32 {
+        self.pierce(damage.saturating_sub(self.defense()))
+    }
+
+    /// Dano que ignora a defesa, mas ainda passa pelo escudo; devolve quanto o escudo absorveu
+    fn pierce(&mut self, damage: u32) -> u32 {
+        let absorbed = self.modifiers_mut().absorb(damage);
+        self.set_health(self.health().saturating_sub(damage - absorbed));
+        absorbed
+    }
+
+    fn shield_up(&mut self, amount: u32) {
+        self.modifiers_mut().shield += amount;
+    }
+
+    fn attack_up(&mut self, attack: u32) {
+        self.modifiers_mut().add(Stat::Attack, attack, ATTACK_BUFF_DURATION);
+    }
+
+    fn defense_up(&mut self, defense: u32) {
+        self.modifiers_mut().add(Stat::Defense, defense, DEFENSE_BUFF_DURATION);
+    }
+
+    /// Aplica um efeito de status seguindo a regra de acúmulo da definição
+    fn status_effect(&mut self, status_effect: StatusEffect, duration: u32, stacking: Stacking) {
+        self.status_effects_mut()
+            .entry(status_effect)
+            .and_modify(|active| stacking.combine(active, duration))
+            .or_insert(ActiveStatus { duration, stacks: 1 });
+    }
+
+    /// Dispara os efeitos do momento indicado: dano e cura por camada, e perda de duração
+    fn trigger_status_effects(&mut self, registry: &StatusRegistry, trigger: StatusTrigger) -> Vec<StatusTickOutcome> {
+        let active: Vec<(StatusEffect, ActiveStatus)> =
+            self.status_effects().iter().map(|(effect, active)| (*effect, *active)).collect();
+        let mut outcomes = Vec::new();
+
+        for (effect, status) in active {
+            let Some(definition) = registry.get(effect) else {
+                continue;
+            };
+            if definition.trigger != trigger {
+                continue;
+            }
+
+            let health_before = self.health();
+            self.set_health(health_before.saturating_sub(definition.tick_damage_for(&status)));
+            let damage = health_before - self.health();
+
+            let health_before = self.health();
+            self.heal(definition.tick_heal_for(&status));
+            let healed = self.health() - health_before;
+
+            let expired = match self.status_effects_mut().get_mut(&effect) {
+                Some(active) => {
+                    active.duration = active.duration.saturating_sub(1);
+                    active.duration == 0
+                }
+                None => false,
+            };
+            if expired {
+                self.status_effects_mut().remove(&effect);
+            }
+
+            outcomes.push(StatusTickOutcome { effect, damage, healed, expired });
+        }
+
+        outcomes
+    }
+
+    /// Fim de um turno da entidade: os bônus perdem um turno de duração
+    fn tick_buffs(&mut self) -> Vec<Buff> {
+        self.modifiers_mut().tick()
+    }
+
+    /// Remove tudo o que só vale durante a batalha
+    fn end_battle(&mut self) {
+        self.modifiers_mut().clear();
+        self.status_effects_mut().clear();
+    }
 }
@@ -1,16 +1,26 @@
+use serde::{Deserialize, Serialize};
 
-
+/// Turnos da batalha. Cada lado age em um turno alternado; no turno do jogador
+/// ele pode usar várias cartas enquanto tiver energia.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct GameTurn {
     pub turn: u32,
     pub max_turn: u32,
+    pub energy: u32,
+    pub max_energy: u32,
+    pub cards_played: u32,
 }
 
 impl GameTurn {
-    pub fn new(max_turn: u32) -> Self {
-        Self { turn: 0, max_turn }
+    pub fn new(max_turn: u32, max_energy: u32) -> Self {
+        Self { turn: 0, max_turn, energy: max_energy, max_energy, cards_played: 0 }
     }
+
+    /// Passa a vez e restaura a energia para o próximo turno
     pub fn next_turn(&mut self) {
         self.turn += 1;
+        self.energy = self.max_energy;
+        self.cards_played = 0;
     }
 
     pub fn reset_turn(&mut self) {
@@ -28,6 +38,18 @@ impl GameTurn {
     pub fn enemy_turn(&self) -> bool {
         self.turn % 2 == 1
     }
-}
 
+    pub fn can_afford(&self, cost: u32) -> bool {
+        cost <= self.energy
+    }
 
+    /// Gasta a energia de uma carta; devolve `false` se não houver energia suficiente
+    pub fn spend_energy(&mut self, cost: u32) -> bool {
+        if !self.can_afford(cost) {
+            return false;
+        }
+        self.energy -= cost;
+        self.cards_played += 1;
+        true
+    }
+}
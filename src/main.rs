@@ -3,15 +3,28 @@ extern crate winapi;
 
 use macroquad::prelude::*;
 
+mod battle_engine;
+mod boss;
+mod card_catalog;
+mod collection;
 mod config;
 mod deck;
 mod effects;
 mod enemy;
+mod enemy_ai;
+mod enemy_roster;
 mod entity;
 mod error;
 mod gameturn;
+mod modifiers;
 mod player;
+mod replay;
+mod rewards;
+mod run;
+mod save;
+mod save_backend;
 mod state;
+mod status_registry;
 
 use state::game_state::GameState;
 
@@ -65,8 +78,12 @@ async fn main() {
     };
 
     game_state.emoji_font = Some(font);
+    game_state.load_game_data().await;
     game_state.load_card_textures().await;
 
+    // Fechar a janela passa pelo GameState, que suspende a batalha em andamento antes de sair
+    prevent_quit();
+
     loop {
         game_state.update();
         game_state.draw();
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// Atributo de combate que um bônus pode alterar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Stat {
+    Attack,
+    Defense,
+}
+
+/// Bônus temporário sobre um atributo, válido só na batalha atual
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Buff {
+    pub stat: Stat,
+    pub amount: u32,
+    /// Turnos do dono que ainda restam
+    pub turns_left: u32,
+}
+
+/// Camada de bônus por cima dos atributos base de uma entidade
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Modifiers {
+    pub buffs: Vec<Buff>,
+    /// Escudo que absorve dano até o próximo turno do dono
+    #[serde(default)]
+    pub shield: u32,
+}
+
+impl Modifiers {
+    pub fn add(&mut self, stat: Stat, amount: u32, turns: u32) {
+        self.buffs.push(Buff { stat, amount, turns_left: turns });
+    }
+
+    pub fn total(&self, stat: Stat) -> u32 {
+        self.buffs
+            .iter()
+            .filter(|buff| buff.stat == stat)
+            .map(|buff| buff.amount)
+            .sum()
+    }
+
+    /// Conta um turno do dono e devolve os bônus que expiraram
+    pub fn tick(&mut self) -> Vec<Buff> {
+        for buff in &mut self.buffs {
+            buff.turns_left = buff.turns_left.saturating_sub(1);
+        }
+        let (expired, active) = self.buffs.drain(..).partition(|buff| buff.turns_left == 0);
+        self.buffs = active;
+        expired
+    }
+
+    /// Desconta o dano do escudo e devolve quanto foi absorvido
+    pub fn absorb(&mut self, damage: u32) -> u32 {
+        let absorbed = damage.min(self.shield);
+        self.shield -= absorbed;
+        absorbed
+    }
+
+    pub fn clear(&mut self) {
+        self.buffs.clear();
+        self.shield = 0;
+    }
+}
@@ -1,11 +1,12 @@
-use crate::deck::{Card, Deck, Hand};
-use crate::effects::StatusEffect;
+use crate::deck::{Card, Hand};
+use crate::effects::{ActiveStatus, StatusEffect};
 use crate::entity::Entity;
-use crate::config::config::*;
-use std::collections::HashMap;
+use crate::modifiers::Modifiers;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Representa um jogador no jogo
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Player {
     /// Nome do jogador
     pub name: String,
@@ -15,9 +16,9 @@ pub struct Player {
     pub health: u32,
     /// Vida máxima do jogador
     pub max_health: u32,
-    /// Valor de ataque atual
+    /// Ataque base, sem os bônus da batalha; o total vem de `Entity::attack`
     pub attack: u32,
-    /// Valor de defesa atual
+    /// Defesa base, sem os bônus da batalha; o total vem de `Entity::defense`
     pub defense: u32,
     /// Nível do jogador
     pub level: u32,
@@ -26,15 +27,17 @@ pub struct Player {
     /// Experiência necessária para o próximo nível
     pub experience_to_next_level: u32,
     /// Efeitos de status ativos no jogador
-    pub status_effects: HashMap<StatusEffect, u32>,
+    pub status_effects: BTreeMap<StatusEffect, ActiveStatus>,
+    /// Bônus temporários da batalha atual
+    #[serde(default)]
+    pub modifiers: Modifiers,
 }
 
 impl Player {
-    pub fn new(name: &str, deck: &mut Deck) -> Player {
-        deck.shuffle();
+    pub fn new(name: &str) -> Player {
         Player {
             name: name.to_string(),
-            hand: Hand::new_from_deck(deck, INITIAL_HAND_SIZE),
+            hand: Hand::new(),
             health: 100,
             max_health: 100,
             attack: 10,
@@ -42,7 +45,8 @@ impl Player {
             level: 1,
             experience: 0,
             experience_to_next_level: 100,
-            status_effects: HashMap::new(),
+            status_effects: BTreeMap::new(),
+            modifiers: Modifiers::default(),
         }
     }
 
@@ -80,62 +84,50 @@ impl Player {
 }
 
 impl Entity for Player {
-    fn discart_card(&mut self, hand: &mut Hand, index: usize) -> Option<Card> {
-        hand.discard(index)
+    fn health(&self) -> u32 {
+        self.health
     }
 
-    fn damage(&mut self, damage: u32) {
-        let damage_final = damage.saturating_sub(self.defense);
-        self.health = self.health.saturating_sub(damage_final);
+    fn max_health(&self) -> u32 {
+        self.max_health
     }
 
-    fn defense_up(&mut self, defense: u32) {
-        self.defense += defense;
+    fn base_attack(&self) -> u32 {
+        self.attack
     }
 
-    fn attack_up(&mut self, attack: u32) {
-        self.attack += attack;
+    fn base_defense(&self) -> u32 {
+        self.defense
     }
 
-    fn heal(&mut self, heal: u32) {
-        self.health = self.health.saturating_add(heal);
-        if self.health > self.max_health {
-            self.health = self.max_health;
-        }
+    fn modifiers(&self) -> &Modifiers {
+        &self.modifiers
     }
 
-    fn status_effect(&mut self, status_effect: StatusEffect, duration: u32) {
-        if let Some(existing_duration) = self.status_effects.get_mut(&status_effect) {
-            *existing_duration += duration;
-        } else {
-            self.status_effects.insert(status_effect, duration);
-        }
+    fn modifiers_mut(&mut self) -> &mut Modifiers {
+        &mut self.modifiers
     }
 
-    fn apply_status_effects(&mut self) {
-        let mut expired_effects = vec![];
-
-        for (effect, duration) in self.status_effects.iter_mut() {
-            match effect {
-                StatusEffect::Poison => {
-                    println!("{} takes 5 damage from poison!", self.name);
-                    self.health = self.health.saturating_sub(5);
-                }
-                StatusEffect::Burn => {
-                    let damage_final = 2 * self.attack.saturating_sub(self.defense);
-                    self.health = self.health.saturating_sub(damage_final);
-                }
-            }
-
-            *duration = duration.saturating_sub(1);
-            if *duration == 0 {
-                expired_effects.push(*effect);
-            }
-        }
+    fn status_effects(&self) -> &BTreeMap<StatusEffect, ActiveStatus> {
+        &self.status_effects
+    }
+
+    fn status_effects_mut(&mut self) -> &mut BTreeMap<StatusEffect, ActiveStatus> {
+        &mut self.status_effects
+    }
+
+    fn discart_card(&mut self, hand: &mut Hand, index: usize) -> Option<Card> {
+        hand.discard(index)
+    }
 
-        for effect in expired_effects {
-            println!("{:?} on {} ended.", effect, self.name);
-            self.status_effects.remove(&effect);
+    fn set_health(&mut self, health: u32) {
+        self.health = health.min(self.max_health);
+    }
+
+    fn heal(&mut self, heal: u32) {
+        self.health = self.health.saturating_add(heal);
+        if self.health > self.max_health {
+            self.health = self.max_health;
         }
     }
 }
@@ -0,0 +1,106 @@
+use crate::battle_engine::{BattleAction, BattleEngine};
+use crate::deck::Deck;
+use crate::enemy::Enemy;
+use crate::error::{GameError, GameResult};
+use crate::player::Player;
+use crate::save_backend::SaveBackend;
+use crate::status_registry::StatusRegistry;
+use serde::{Deserialize, Serialize};
+
+/// Tudo o que é preciso para reproduzir uma batalha: o estado antes de
+/// embaralhar e distribuir as cartas, a semente e as ações na ordem em que
+/// foram aplicadas.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BattleReplay {
+    pub seed: u64,
+    pub max_turns: u32,
+    pub player: Player,
+    pub enemies: Vec<Enemy>,
+    pub deck: Deck,
+    /// Regras dos efeitos de status usadas na batalha
+    #[serde(default)]
+    pub statuses: StatusRegistry,
+    pub actions: Vec<BattleAction>,
+}
+
+impl BattleReplay {
+    /// Cria um motor novo no mesmo ponto de partida da batalha gravada
+    pub fn to_engine(&self) -> BattleEngine {
+        BattleEngine::new(
+            self.player.clone(),
+            self.enemies.clone(),
+            self.deck.clone(),
+            self.statuses.clone(),
+            self.max_turns,
+            self.seed,
+        )
+    }
+
+    /// Motor no ponto em que a gravação parou, reaplicando todas as ações
+    pub fn resume(&self) -> BattleEngine {
+        let mut engine = self.to_engine();
+        for action in &self.actions {
+            engine.apply(*action);
+        }
+        engine
+    }
+
+    pub fn save(&self, backend: &impl SaveBackend, key: &str) -> GameResult<()> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| GameError::SaveLoadError(e.to_string()))?;
+        backend.write(key, &json).map_err(|e| GameError::SaveLoadError(format!("{}: {}", backend.describe(key), e)))
+    }
+
+    /// Gravação guardada na chave, ou `None` se ainda não houver nenhuma
+    pub fn load(backend: &impl SaveBackend, key: &str) -> GameResult<Option<Self>> {
+        let Some(json) = backend.read(key).map_err(|e| GameError::SaveLoadError(format!("{}: {}", backend.describe(key), e)))? else {
+            return Ok(None);
+        };
+        serde_json::from_str(&json).map(Some).map_err(|e| GameError::SaveLoadError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::battle_engine::tests::auto_play;
+    use crate::battle_engine::BattleEngine;
+    use crate::card_catalog::CardCatalog;
+    use crate::deck::Deck;
+    use crate::enemy_roster::EnemyRoster;
+    use crate::player::Player;
+    use crate::status_registry::StatusRegistry;
+
+    fn battle(seed: u64) -> BattleEngine {
+        let catalog = CardCatalog::embedded();
+        let roster = EnemyRoster::embedded();
+        let enemies = roster.enemies.iter().take(2).map(|definition| definition.to_enemy(&catalog)).collect();
+        BattleEngine::new(Player::new("Tester"), enemies, Deck::new(&catalog), StatusRegistry::embedded(), 50, seed)
+    }
+
+    fn snapshot(engine: &BattleEngine) -> String {
+        serde_json::to_string(&(&engine.player, &engine.enemies, &engine.piles, engine.turn.turn)).unwrap()
+    }
+
+    #[test]
+    fn replay_reproduces_the_battle() {
+        for seed in 0..20 {
+            let mut engine = battle(seed);
+            auto_play(&mut engine, 200);
+
+            let replayed = engine.replay().resume();
+            assert_eq!(snapshot(&replayed), snapshot(&engine), "seed {}", seed);
+            assert_eq!(replayed.outcome(), engine.outcome());
+        }
+    }
+
+    #[test]
+    fn replay_survives_json() {
+        let mut engine = battle(7);
+        auto_play(&mut engine, 40);
+
+        let json = serde_json::to_string(engine.replay()).unwrap();
+        let replay: super::BattleReplay = serde_json::from_str(&json).unwrap();
+        assert_eq!(replay.actions, engine.replay().actions);
+        assert_eq!(snapshot(&replay.resume()), snapshot(&engine));
+    }
+}
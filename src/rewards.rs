@@ -0,0 +1,54 @@
+use crate::card_catalog::{CardCatalog, Rarity};
+use crate::config::config::*;
+use crate::deck::Card;
+use ::rand::Rng;
+
+/// Peso de sorteio de uma raridade; inimigos mais fortes e mais vencidos puxam para as raras
+pub fn rarity_weight(rarity: Rarity, enemy_level: u32, times_defeated: u32) -> f32 {
+    let bonus = (enemy_level.saturating_sub(1) + times_defeated) as f32 * REWARD_RARITY_BONUS;
+    match rarity {
+        Rarity::Common => REWARD_WEIGHT_COMMON,
+        Rarity::Uncommon => REWARD_WEIGHT_UNCOMMON + bonus,
+        Rarity::Rare => REWARD_WEIGHT_RARE + bonus,
+        Rarity::Epic => REWARD_WEIGHT_EPIC + bonus / 2.0,
+    }
+}
+
+/// Ouro ganho ao vencer um inimigo do nível indicado
+pub fn gold_reward(enemy_level: u32) -> u32 {
+    BASE_GOLD_GAIN + enemy_level * GOLD_PER_ENEMY_LEVEL
+}
+
+/// Sorteia cartas diferentes do catálogo para a escolha de recompensa após uma vitória
+pub fn roll_card_rewards<R: Rng>(catalog: &CardCatalog, enemy_level: u32, times_defeated: u32, rng: &mut R) -> Vec<Card> {
+    roll_cards(catalog, enemy_level, times_defeated, CARD_REWARD_CHOICES, rng)
+}
+
+/// Sorteia até `count` cartas diferentes, com os pesos de raridade das recompensas
+pub fn roll_cards<R: Rng>(catalog: &CardCatalog, enemy_level: u32, times_defeated: u32, count: usize, rng: &mut R) -> Vec<Card> {
+    let mut candidates: Vec<(Card, f32)> = catalog
+        .cards
+        .iter()
+        .map(|definition| {
+            let weight = rarity_weight(definition.rarity, enemy_level, times_defeated);
+            (definition.to_card(), weight)
+        })
+        .collect();
+
+    let mut rewards = Vec::with_capacity(count);
+    while rewards.len() < count && !candidates.is_empty() {
+        let total: f32 = candidates.iter().map(|(_, weight)| weight).sum();
+        let mut roll = rng.gen_range(0.0..total);
+        let mut index = candidates.len() - 1;
+        for (i, (_, weight)) in candidates.iter().enumerate() {
+            if roll < *weight {
+                index = i;
+                break;
+            }
+            roll -= weight;
+        }
+        rewards.push(candidates.swap_remove(index).0);
+    }
+
+    rewards
+}
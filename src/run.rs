@@ -0,0 +1,327 @@
+use crate::card_catalog::CardCatalog;
+use crate::collection::CardCollection;
+use crate::config::config::*;
+use crate::deck::{Card, Deck};
+use crate::enemy::Enemy;
+use crate::enemy_roster::{EnemyDefinition, EnemyRoster};
+use crate::rewards::{roll_card_rewards, roll_cards};
+use ::rand::Rng;
+use ::rand::SeedableRng;
+use ::rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+/// Tipo de parada no mapa da run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NodeKind {
+    Battle,
+    Elite,
+    Rest,
+    Shop,
+    Event,
+    Boss,
+}
+
+impl NodeKind {
+    pub fn icon(self) -> &'static str {
+        match self {
+            NodeKind::Battle => "⚔️",
+            NodeKind::Elite => "💀",
+            NodeKind::Rest => "🔥",
+            NodeKind::Shop => "💰",
+            NodeKind::Event => "❓",
+            NodeKind::Boss => "👑",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NodeKind::Battle => "Battle",
+            NodeKind::Elite => "Elite",
+            NodeKind::Rest => "Rest Site",
+            NodeKind::Shop => "Shop",
+            NodeKind::Event => "Event",
+            NodeKind::Boss => "Boss",
+        }
+    }
+}
+
+/// Peso de sorteio de cada tipo de parada nos andares do meio do mapa
+const NODE_WEIGHTS: [(NodeKind, u32); 5] = [
+    (NodeKind::Battle, 45),
+    (NodeKind::Event, 20),
+    (NodeKind::Elite, 15),
+    (NodeKind::Shop, 10),
+    (NodeKind::Rest, 10),
+];
+
+/// Parada do mapa; `x` vai de 0 a 1 e serve para ligar os andares e desenhar o mapa
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapNode {
+    pub kind: NodeKind,
+    pub row: usize,
+    pub x: f32,
+    /// Paradas do andar seguinte alcançáveis a partir desta
+    pub next: Vec<usize>,
+    /// Inimigo do roster enfrentado nas paradas de batalha
+    pub enemy_id: Option<u32>,
+}
+
+/// Mapa da run: andares de paradas ligadas, terminando no chefe
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunMap {
+    pub nodes: Vec<MapNode>,
+    pub rows: usize,
+}
+
+impl RunMap {
+    /// Gera o mapa a partir da semente; a mesma semente e o mesmo roster geram o mesmo mapa
+    pub fn generate(seed: u64, roster: &EnemyRoster) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let rows = RUN_FLOORS + 1;
+        let mut nodes: Vec<MapNode> = Vec::new();
+        let mut previous: Vec<usize> = Vec::new();
+
+        for row in 0..rows {
+            let count = if row == RUN_FLOORS {
+                1
+            } else {
+                rng.gen_range(RUN_MIN_NODES_PER_FLOOR..=RUN_MAX_NODES_PER_FLOOR)
+            };
+            let current: Vec<usize> = (0..count)
+                .map(|column| {
+                    let kind = Self::roll_kind(row, &mut rng);
+                    let x = if count == 1 { 0.5 } else { column as f32 / (count - 1) as f32 };
+                    let enemy_id = Self::roll_enemy(kind, row, roster, &mut rng);
+                    nodes.push(MapNode { kind, row, x, next: Vec::new(), enemy_id });
+                    nodes.len() - 1
+                })
+                .collect();
+
+            // Cada parada segue para a mais próxima do andar seguinte e, às vezes, também para uma vizinha
+            for &from in &previous {
+                let nearest = Self::nearest(&nodes, &current, nodes[from].x);
+                nodes[from].next.push(current[nearest]);
+                if rng.gen_bool(RUN_BRANCH_CHANCE) {
+                    let neighbor = if rng.gen_bool(0.5) { nearest + 1 } else { nearest.wrapping_sub(1) };
+                    if let Some(&to) = current.get(neighbor) {
+                        nodes[from].next.push(to);
+                    }
+                }
+            }
+            // Nenhuma parada fica fora de todos os caminhos
+            for &to in &current {
+                let reachable = previous.iter().any(|from| nodes[*from].next.contains(&to));
+                if !previous.is_empty() && !reachable {
+                    let nearest = Self::nearest(&nodes, &previous, nodes[to].x);
+                    nodes[previous[nearest]].next.push(to);
+                }
+            }
+            for &from in &previous {
+                nodes[from].next.sort_unstable();
+                nodes[from].next.dedup();
+            }
+
+            previous = current;
+        }
+
+        Self { nodes, rows }
+    }
+
+    fn roll_kind(row: usize, rng: &mut StdRng) -> NodeKind {
+        if row == 0 {
+            return NodeKind::Battle;
+        }
+        if row == RUN_FLOORS {
+            return NodeKind::Boss;
+        }
+        if row == RUN_FLOORS - 1 {
+            return NodeKind::Rest;
+        }
+        // Sem elites logo no começo
+        let weights: Vec<(NodeKind, u32)> = NODE_WEIGHTS
+            .iter()
+            .copied()
+            .filter(|(kind, _)| row >= 2 || *kind != NodeKind::Elite)
+            .collect();
+        let total: u32 = weights.iter().map(|(_, weight)| weight).sum();
+        let mut roll = rng.gen_range(0..total);
+        for (kind, weight) in &weights {
+            if roll < *weight {
+                return *kind;
+            }
+            roll -= weight;
+        }
+        NodeKind::Battle
+    }
+
+    /// Inimigos comuns ficam mais fortes a cada andar; elites vêm da metade mais forte e o chefe é o último do roster
+    fn roll_enemy(kind: NodeKind, row: usize, roster: &EnemyRoster, rng: &mut StdRng) -> Option<u32> {
+        let (boss, regulars) = roster.enemies.split_last()?;
+        let pool = if regulars.is_empty() { std::slice::from_ref(boss) } else { regulars };
+        let definition = match kind {
+            NodeKind::Battle => {
+                let reach = (row * pool.len() / RUN_FLOORS).min(pool.len() - 1);
+                &pool[rng.gen_range(0..=reach)]
+            }
+            NodeKind::Elite => &pool[rng.gen_range(pool.len() / 2..pool.len())],
+            NodeKind::Boss => boss,
+            _ => return None,
+        };
+        Some(definition.id)
+    }
+
+    fn nearest(nodes: &[MapNode], candidates: &[usize], x: f32) -> usize {
+        candidates
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (nodes[**a].x - x).abs().total_cmp(&(nodes[**b].x - x).abs()))
+            .map_or(0, |(index, _)| index)
+    }
+}
+
+/// Run em andamento: o mapa, o caminho feito, a vida que passa de uma parada para a outra e o baralho da run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunState {
+    pub seed: u64,
+    pub map: RunMap,
+    /// Paradas concluídas, na ordem do caminho
+    pub path: Vec<usize>,
+    /// Parada de batalha em andamento
+    #[serde(default)]
+    pub active_node: Option<usize>,
+    pub health: u32,
+    pub max_health: u32,
+    /// Baralho da run; tudo o que é possuído está no baralho
+    pub deck: CardCollection,
+}
+
+impl RunState {
+    pub fn new(seed: u64, roster: &EnemyRoster, max_health: u32, deck: CardCollection) -> Self {
+        Self {
+            seed,
+            map: RunMap::generate(seed, roster),
+            path: Vec::new(),
+            active_node: None,
+            health: max_health,
+            max_health,
+            deck,
+        }
+    }
+
+    pub fn current(&self) -> Option<usize> {
+        self.path.last().copied()
+    }
+
+    /// Paradas que o jogador pode escolher agora
+    pub fn available(&self) -> Vec<usize> {
+        match self.current() {
+            Some(node) => self.map.nodes[node].next.clone(),
+            None => (0..self.map.nodes.len()).filter(|node| self.map.nodes[*node].row == 0).collect(),
+        }
+    }
+
+    pub fn floor(&self) -> usize {
+        self.path.len()
+    }
+
+    /// A run termina quando o chefe é derrotado
+    pub fn is_complete(&self) -> bool {
+        self.current().is_some_and(|node| self.map.nodes[node].kind == NodeKind::Boss)
+    }
+
+    pub fn visit(&mut self, node: usize) {
+        self.path.push(node);
+        self.active_node = None;
+    }
+
+    /// Semente de uma parada: batalhas e eventos se repetem com a mesma semente da run
+    pub fn node_seed(&self, node: usize) -> u64 {
+        self.seed ^ (node as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+
+    pub fn heal(&mut self, percent: f32) -> u32 {
+        let amount = ((self.max_health as f32 * percent) as u32).min(self.max_health - self.health);
+        self.health += amount;
+        amount
+    }
+
+    pub fn build_deck(&self, catalog: &CardCatalog) -> Deck {
+        self.deck.build_deck(catalog)
+    }
+
+    /// Cartas oferecidas depois de uma vitória; andares mais altos e elites puxam para as raras
+    pub fn card_choices(&self, node: usize, catalog: &CardCatalog) -> Vec<Card> {
+        let map_node = &self.map.nodes[node];
+        let bonus = if map_node.kind == NodeKind::Elite { RUN_ELITE_REWARD_BONUS } else { 0 };
+        let mut rng = StdRng::seed_from_u64(self.node_seed(node).wrapping_add(1));
+        roll_card_rewards(catalog, map_node.row as u32 + 1, bonus, &mut rng)
+    }
+
+    /// Cartas à venda na loja da parada, sorteadas pela semente da parada
+    pub fn shop_offers(&self, node: usize, catalog: &CardCatalog) -> Vec<Card> {
+        let mut rng = StdRng::seed_from_u64(self.node_seed(node).wrapping_add(2));
+        roll_cards(catalog, self.map.nodes[node].row as u32 + 1, 0, SHOP_OFFER_COUNT, &mut rng)
+    }
+
+    /// Inimigos da parada com os atributos do andar; elites são mais fortes e trazem a escolta
+    pub fn encounter(&self, node: usize, roster: &EnemyRoster, catalog: &CardCatalog) -> Vec<Enemy> {
+        let map_node = &self.map.nodes[node];
+        let Some(definition) = map_node.enemy_id.and_then(|id| roster.get(id)) else {
+            return Vec::new();
+        };
+        let mut multiplier = 1.0 + map_node.row as f32 * RUN_FLOOR_SCALING;
+        if map_node.kind == NodeKind::Elite {
+            multiplier *= RUN_ELITE_MULTIPLIER;
+        }
+        let scaled = |definition: &EnemyDefinition| {
+            let mut enemy = definition.to_enemy(catalog);
+            enemy.max_health = (enemy.max_health as f32 * multiplier) as u32;
+            enemy.health = enemy.max_health;
+            enemy.attack = (enemy.attack as f32 * multiplier) as u32;
+            enemy.defense = (enemy.defense as f32 * multiplier) as u32;
+            enemy
+        };
+
+        let mut lead = scaled(definition);
+        lead.phases = definition.phases.iter().map(|phase| phase.to_phase(catalog, roster)).collect();
+        let mut enemies = vec![lead];
+        if map_node.kind == NodeKind::Elite {
+            enemies.extend(
+                definition
+                    .escort
+                    .iter()
+                    .filter_map(|id| roster.get(*id))
+                    .map(scaled)
+                    .take(MAX_ENCOUNTER_ENEMIES - 1),
+            );
+        }
+        enemies
+    }
+
+    /// Resolve um evento sorteado pela semente da parada e descreve o que aconteceu
+    pub fn resolve_event(&mut self, node: usize, catalog: &CardCatalog) -> String {
+        let mut rng = StdRng::seed_from_u64(self.node_seed(node));
+        match rng.gen_range(0..3) {
+            0 => {
+                let healed = self.heal(RUN_EVENT_HEAL_PERCENT);
+                format!("⛲ A fountain of fresh ink restores {} HP.", healed)
+            }
+            1 => {
+                self.max_health += RUN_EVENT_MAX_HEALTH;
+                self.health += RUN_EVENT_MAX_HEALTH;
+                format!("🥋 An old master trains you: +{} max HP.", RUN_EVENT_MAX_HEALTH)
+            }
+            _ => {
+                let damage = ((self.max_health as f32 * RUN_EVENT_DAMAGE_PERCENT) as u32).min(self.health - 1);
+                self.health -= damage;
+                match roll_card_rewards(catalog, self.floor() as u32 + 1, 0, &mut rng).first() {
+                    Some(card) => {
+                        self.deck.add_card_to_deck(&card.id);
+                        format!("📜 You dig through crumpled paper: -{} HP, found {}.", damage, card.name)
+                    }
+                    None => format!("📜 You dig through crumpled paper: -{} HP.", damage),
+                }
+            }
+        }
+    }
+}
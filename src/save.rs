@@ -0,0 +1,501 @@
+use crate::config::config::*;
+use crate::error::{GameError, GameResult};
+use crate::player::Player;
+use crate::replay::BattleReplay;
+use crate::state::game_state::SaveData;
+use crate::save_backend::SaveBackend;
+use serde_json::Value;
+
+/// Migração de um save da versão `i` para a `i + 1`, feita sobre o JSON cru
+type Migration = fn(&mut Value) -> GameResult<()>;
+
+/// Cadeia de migrações; a posição na lista é a versão de origem
+const MIGRATIONS: &[Migration] = &[migrate_v0_to_v1, migrate_v1_to_v2];
+
+/// Versão gravada nos saves novos
+pub const SAVE_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// v0 → v1: saves sem versão guardavam no jogador os bônus de ataque e defesa das cartas acumulados;
+/// na v1 eles são os valores base, restaurados como estão ao carregar
+fn migrate_v0_to_v1(save: &mut Value) -> GameResult<()> {
+    let base = Player::new("");
+    if let Some(player) = save.get_mut("persistent_player").and_then(Value::as_object_mut) {
+        player.insert("attack".to_string(), base.attack.into());
+        player.insert("defense".to_string(), base.defense.into());
+    }
+    Ok(())
+}
+
+/// v1 → v2: a batalha suspensa era a gravação, refeita ação por ação ao carregar; na v2 é o estado do motor.
+/// Uma gravação que não pode ser lida só descarta a batalha suspensa, sem perder o resto do save.
+fn migrate_v1_to_v2(save: &mut Value) -> GameResult<()> {
+    let Some(engine) = save.pointer_mut("/battle/battle/engine") else {
+        return Ok(());
+    };
+    let resumed = serde_json::from_value::<BattleReplay>(engine.take())
+        .map_err(|e| e.to_string())
+        .and_then(|replay| serde_json::to_value(replay.resume()).map_err(|e| e.to_string()));
+    match resumed {
+        Ok(state) => *engine = state,
+        Err(error) => {
+            eprintln!("⚠️ Discarding the suspended battle: {}", error);
+            save["battle"] = Value::Null;
+        }
+    }
+    Ok(())
+}
+
+/// Leva o JSON de um save de qualquer versão conhecida até a atual
+pub fn migrate(mut save: Value) -> GameResult<Value> {
+    let Some(object) = save.as_object() else {
+        return Err(GameError::SaveLoadError("the save is not a JSON object".to_string()));
+    };
+    let version = match object.get("version") {
+        None => 0,
+        Some(value) => value
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| GameError::SaveLoadError(format!("invalid save version {}", value)))?,
+    };
+    if version > SAVE_VERSION {
+        return Err(GameError::SaveLoadError(format!(
+            "the save has version {}, newer than the supported {}",
+            version, SAVE_VERSION
+        )));
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut save)?;
+    }
+    save["version"] = SAVE_VERSION.into();
+    Ok(save)
+}
+
+fn parse(json: &str) -> Result<SaveData, String> {
+    let save = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let save = migrate(save).map_err(|e| match e {
+        GameError::SaveLoadError(message) => message,
+        other => other.to_string(),
+    })?;
+    serde_json::from_value(save).map_err(|e| e.to_string())
+}
+
+fn read_json(backend: &impl SaveBackend, key: &str) -> GameResult<Option<String>> {
+    backend.read(key).map_err(|e| GameError::SaveLoadError(format!("{}: {}", backend.describe(key), e)))
+}
+
+/// Lê e migra o save sem mexer em nada guardado; um save ilegível só vira erro
+fn peek(backend: &impl SaveBackend, key: &str) -> GameResult<Option<SaveData>> {
+    let Some(json) = read_json(backend, key)? else {
+        return Ok(None);
+    };
+    parse(&json).map(Some).map_err(GameError::SaveLoadError)
+}
+
+/// Lê e migra o save; sem save na chave não há progresso a carregar.
+/// Um save ilegível é movido para um backup, para não ser sobrescrito pelo próximo salvamento.
+pub fn load(backend: &impl SaveBackend, key: &str) -> GameResult<Option<SaveData>> {
+    let Some(json) = read_json(backend, key)? else {
+        return Ok(None);
+    };
+
+    parse(&json).map(Some).map_err(|error| match backup_corrupt(backend, key) {
+        Ok(backup) => GameError::SaveLoadError(format!("{} (the save was moved to {})", error, backend.describe(&backup))),
+        Err(backup_error) => GameError::SaveLoadError(format!("{} ({})", error, backup_error)),
+    })
+}
+
+/// Grava o save por inteiro, sem nunca deixá-lo pela metade.
+/// Num checkpoint uma cópia do save entra também na fila de backups.
+pub fn store(backend: &impl SaveBackend, key: &str, save: &SaveData, checkpoint: bool) -> GameResult<()> {
+    let to_error = |e: std::io::Error| GameError::SaveLoadError(format!("{}: {}", backend.describe(key), e));
+    let json = serde_json::to_string_pretty(save).map_err(|e| GameError::SaveLoadError(e.to_string()))?;
+
+    backend.write(key, &json).map_err(to_error)?;
+    if checkpoint {
+        rotate_backups(backend, key, &json).map_err(to_error)?;
+    }
+    Ok(())
+}
+
+fn key_stem(key: &str) -> &str {
+    key.strip_suffix(".json").unwrap_or(key)
+}
+
+/// Backup `n` do save; o 1 é o mais recente
+fn backup_key(key: &str, n: usize) -> String {
+    format!("{}.bak{}.json", key_stem(key), n)
+}
+
+/// Empurra os backups uma posição, descartando o mais antigo, e grava o save recém-salvo no primeiro
+fn rotate_backups(backend: &impl SaveBackend, key: &str, json: &str) -> std::io::Result<()> {
+    for n in (1..SAVE_BACKUP_COUNT).rev() {
+        let older = backup_key(key, n);
+        if backend.exists(&older) {
+            backend.rename(&older, &backup_key(key, n + 1))?;
+        }
+    }
+    backend.write(&backup_key(key, 1), json)
+}
+
+/// Último checkpoint legível: o save e depois os backups, do mais recente ao mais antigo.
+/// Os ilegíveis são postos de lado pelo `load` e a busca segue para o próximo.
+pub fn load_latest(backend: &impl SaveBackend, key: &str) -> GameResult<Option<SaveData>> {
+    let mut last_error = None;
+    let candidates = std::iter::once(key.to_string()).chain((1..=SAVE_BACKUP_COUNT).map(|n| backup_key(key, n)));
+    for candidate in candidates {
+        match load(backend, &candidate) {
+            Ok(Some(save)) => return Ok(Some(save)),
+            Ok(None) => {}
+            Err(error) => last_error = Some(error),
+        }
+    }
+    last_error.map_or(Ok(None), Err)
+}
+
+/// Backup legível mais recente e a posição dele na fila, sem mexer em nenhum arquivo
+pub fn latest_backup(backend: &impl SaveBackend, key: &str) -> Option<(usize, SaveData)> {
+    (1..=SAVE_BACKUP_COUNT).find_map(|n| peek(backend, &backup_key(key, n)).ok().flatten().map(|save| (n, save)))
+}
+
+/// Renomeia o save corrompido com a data, ao lado do original
+fn backup_corrupt(backend: &impl SaveBackend, key: &str) -> GameResult<String> {
+    let timestamp = macroquad::miniquad::date::now() as u64;
+    let stem = key_stem(key);
+    let mut backup = format!("{}.corrupt-{}.json", stem, timestamp);
+    let mut copy = 1;
+    while backend.exists(&backup) {
+        backup = format!("{}.corrupt-{}-{}.json", stem, timestamp, copy);
+        copy += 1;
+    }
+    backend
+        .rename(key, &backup)
+        .map_err(|e| GameError::SaveLoadError(format!("could not back up {}: {}", backend.describe(key), e)))?;
+    Ok(backup)
+}
+
+pub fn slot_key(slot: usize) -> String {
+    format!("slot_{}.json", slot + 1)
+}
+
+/// Última batalha gravada no slot, vista de novo na tela de fim de jogo
+pub fn replay_key(slot: usize) -> String {
+    format!("slot_{}.replay.json", slot + 1)
+}
+
+/// Traz o save antigo da pasta onde o jogo foi aberto para o primeiro slot, se ele estiver vazio
+#[cfg(not(target_arch = "wasm32"))]
+pub fn adopt_legacy_save(backend: &impl SaveBackend) -> GameResult<()> {
+    let legacy = std::path::Path::new(LEGACY_SAVE_FILE);
+    let first_slot = slot_key(0);
+    if !legacy.exists() || backend.exists(&first_slot) {
+        return Ok(());
+    }
+    let to_error = |e: std::io::Error| GameError::SaveLoadError(format!("could not move {}: {}", LEGACY_SAVE_FILE, e));
+    let json = std::fs::read_to_string(legacy).map_err(to_error)?;
+    backend.write(&first_slot, &json).map_err(to_error)?;
+    std::fs::remove_file(legacy).map_err(to_error)
+}
+
+/// O navegador nunca teve o save antigo
+#[cfg(target_arch = "wasm32")]
+pub fn adopt_legacy_save(_backend: &impl SaveBackend) -> GameResult<()> {
+    Ok(())
+}
+
+/// O que o seletor de perfis mostra de cada slot
+pub struct SlotSummary {
+    pub player_name: String,
+    pub level: u32,
+    pub enemies_defeated: usize,
+    /// Por que o save principal não pôde ser usado, quando o resumo vem de um backup
+    pub warning: Option<String>,
+}
+
+impl SlotSummary {
+    fn from_save(save: &SaveData, warning: Option<String>) -> Self {
+        Self {
+            player_name: save.player_name.clone(),
+            level: save.persistent_player.as_ref().map_or(1, |player| player.level),
+            enemies_defeated: save.enemies.iter().filter(|enemy| enemy.is_defeated).count(),
+            warning,
+        }
+    }
+}
+
+/// Resumo do último checkpoint legível do slot, ou `None` se estiver vazio.
+/// Só lê: um save corrompido fica onde está até o perfil ser aberto, e o erro volta no resumo do backup
+/// ou, sem backup legível, como `Err`.
+pub fn read_slot(backend: &impl SaveBackend, slot: usize) -> GameResult<Option<SlotSummary>> {
+    let key = slot_key(slot);
+    let main_error = match peek(backend, &key) {
+        Ok(Some(save)) => return Ok(Some(SlotSummary::from_save(&save, None))),
+        Ok(None) => None,
+        Err(error) => Some(error),
+    };
+    let Some((n, save)) = latest_backup(backend, &key) else {
+        return main_error.map_or(Ok(None), Err);
+    };
+    let reason = match main_error {
+        Some(GameError::SaveLoadError(message)) => format!("the save is unreadable ({})", message),
+        Some(other) => other.to_string(),
+        None => "the save is missing".to_string(),
+    };
+    let warning = format!("Slot {}: {}; showing checkpoint backup {}", slot + 1, reason, n);
+    Ok(Some(SlotSummary::from_save(&save, Some(warning))))
+}
+
+/// Copia o slot com os backups e a gravação. O save principal da cópia é o que o `read_slot` mostra:
+/// o save do slot ou, se ele estiver ilegível, o backup mais recente que ainda pode ser lido.
+pub fn copy_slot(backend: &impl SaveBackend, from: usize, to: usize) -> GameResult<()> {
+    let to_error = |e: std::io::Error| GameError::SaveLoadError(format!("could not copy slot {}: {}", from + 1, e));
+    let from_key = slot_key(from);
+    let source = match peek(backend, &from_key) {
+        Ok(Some(_)) => from_key.clone(),
+        main => match latest_backup(backend, &from_key) {
+            Some((n, _)) => backup_key(&from_key, n),
+            None => {
+                main?;
+                return Err(GameError::SaveLoadError(format!("slot {} is empty", from + 1)));
+            }
+        },
+    };
+    let json = read_json(backend, &source)?.unwrap_or_default();
+    let backups = (1..=SAVE_BACKUP_COUNT)
+        .map(|n| backend.read(&backup_key(&from_key, n)).map_err(to_error))
+        .collect::<GameResult<Vec<_>>>()?;
+    let replay = backend.read(&replay_key(from)).map_err(to_error)?;
+
+    // Um save ilegível também aparece como slot vazio, e não pode ser apagado por uma cópia
+    let to_key = slot_key(to);
+    if backend.exists(&to_key) {
+        return Err(GameError::SaveLoadError(format!("slot {} is not empty", to + 1)));
+    }
+    delete_slot(backend, to)?;
+    backend.write(&to_key, &json).map_err(to_error)?;
+    for (n, backup) in (1..).zip(backups) {
+        if let Some(backup) = backup {
+            backend.write(&backup_key(&to_key, n), &backup).map_err(to_error)?;
+        }
+    }
+    if let Some(replay) = replay {
+        backend.write(&replay_key(to), &replay).map_err(to_error)?;
+    }
+    Ok(())
+}
+
+/// Apaga o save do slot junto com os backups e a gravação
+pub fn delete_slot(backend: &impl SaveBackend, slot: usize) -> GameResult<()> {
+    let key = slot_key(slot);
+    let keys = [key.clone(), replay_key(slot)].into_iter().chain((1..=SAVE_BACKUP_COUNT).map(|n| backup_key(&key, n)));
+    for key in keys {
+        backend
+            .remove(&key)
+            .map_err(|e| GameError::SaveLoadError(format!("could not delete {}: {}", backend.describe(&key), e)))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle_engine::BattleEngine;
+    use crate::battle_engine::tests::auto_play;
+    use crate::card_catalog::CardCatalog;
+    use crate::deck::Deck;
+    use crate::enemy_roster::EnemyRoster;
+    use crate::save_backend::FileBackend;
+    use crate::status_registry::StatusRegistry;
+    use std::path::PathBuf;
+
+    /// Pasta vazia só para o teste, apagada no fim
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("dani_save_{}_{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        fn backend(&self) -> FileBackend {
+            FileBackend::new(self.0.clone())
+        }
+
+        fn files(&self) -> Vec<String> {
+            let mut files: Vec<String> = std::fs::read_dir(&self.0)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            files.sort();
+            files
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    const SAVE_V0: &str = include_str!("../tests/fixtures/save_v0.json");
+
+    #[test]
+    fn v0_save_is_migrated_to_base_stats() {
+        let save = parse(SAVE_V0).unwrap();
+        let base = Player::new("");
+
+        assert_eq!(save.version, SAVE_VERSION);
+        let player = save.persistent_player.as_ref().unwrap().to_player();
+        assert_eq!((player.attack, player.defense), (base.attack, base.defense));
+        assert_eq!((player.health, player.max_health, player.level, player.experience), (125, 125, 2, 30));
+        assert_eq!(save.player_name, "Dani");
+        assert_eq!(save.sound_settings.music_volume, 0.4);
+        assert!(!save.sound_settings.sfx_enabled);
+        assert_eq!(save.enemies.len(), 2);
+        assert!(save.enemies[0].is_defeated);
+        assert!(save.collection.is_empty());
+        assert_eq!(save.gold, 0);
+    }
+
+    #[test]
+    fn current_saves_are_not_migrated_again() {
+        let mut save: Value = serde_json::from_str(SAVE_V0).unwrap();
+        save["version"] = SAVE_VERSION.into();
+        save["persistent_player"]["attack"] = 17.into();
+
+        let migrated = migrate(save).unwrap();
+        assert_eq!(migrated["persistent_player"]["attack"], 17);
+    }
+
+    #[test]
+    fn newer_saves_are_rejected() {
+        let mut save: Value = serde_json::from_str(SAVE_V0).unwrap();
+        save["version"] = (SAVE_VERSION + 1).into();
+        assert!(migrate(save).is_err());
+    }
+
+    #[test]
+    fn corrupt_save_is_moved_aside() {
+        let dir = TempDir::new("corrupt");
+        let backend = dir.backend();
+        backend.write("slot_1.json", "{ not json").unwrap();
+
+        let error = load(&backend, "slot_1.json").err().expect("corrupt save must fail").to_string();
+
+        assert!(error.contains("moved to"), "{}", error);
+        let files = dir.files();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].starts_with("slot_1.corrupt-") && files[0].ends_with(".json"), "{:?}", files);
+        assert_eq!(std::fs::read_to_string(dir.0.join(&files[0])).unwrap(), "{ not json");
+        assert!(load(&backend, "slot_1.json").unwrap().is_none());
+    }
+
+    #[test]
+    fn read_slot_reports_corruption_without_touching_the_files() {
+        let dir = TempDir::new("read_slot");
+        let backend = dir.backend();
+        backend.write("slot_1.json", "{ not json").unwrap();
+
+        assert!(read_slot(&backend, 0).is_err());
+        backend.write("slot_1.bak1.json", SAVE_V0).unwrap();
+        let files = dir.files();
+
+        let summary = read_slot(&backend, 0).unwrap().expect("the backup must be shown");
+        assert_eq!((summary.player_name.as_str(), summary.level), ("Dani", 2));
+        let warning = summary.warning.expect("a backup summary must say so");
+        assert!(warning.contains("unreadable") && warning.contains("backup 1"), "{}", warning);
+        assert_eq!(dir.files(), files);
+    }
+
+    #[test]
+    fn copy_slot_copies_what_read_slot_shows_and_the_backups() {
+        let dir = TempDir::new("copy_slot");
+        let backend = dir.backend();
+        let mut older: Value = serde_json::from_str(SAVE_V0).unwrap();
+        older["player_name"] = "Older".into();
+        let older = older.to_string();
+        backend.write("slot_1.json", "{ not json").unwrap();
+        backend.write("slot_1.bak1.json", SAVE_V0).unwrap();
+        backend.write("slot_1.bak2.json", &older).unwrap();
+        backend.write("slot_1.replay.json", "{}").unwrap();
+        let source_files = dir.files();
+        backend.write("slot_2.bak3.json", "stale").unwrap();
+
+        copy_slot(&backend, 0, 1).unwrap();
+
+        let copy = read_slot(&backend, 1).unwrap().unwrap();
+        assert_eq!(copy.player_name, read_slot(&backend, 0).unwrap().unwrap().player_name);
+        assert!(copy.warning.is_none());
+        assert_eq!(backend.read("slot_2.bak1.json").unwrap().as_deref(), Some(SAVE_V0));
+        assert_eq!(backend.read("slot_2.bak2.json").unwrap(), Some(older));
+        assert!(!backend.exists("slot_2.bak3.json"));
+        assert_eq!(backend.read("slot_2.replay.json").unwrap().as_deref(), Some("{}"));
+        assert!(source_files.iter().all(|file| dir.files().contains(file)));
+
+        backend.write("slot_3.json", "{ not json").unwrap();
+        assert!(copy_slot(&backend, 0, 2).is_err());
+        assert_eq!(backend.read("slot_3.json").unwrap().as_deref(), Some("{ not json"));
+    }
+
+    fn v1_save_with_battle(engine: Value) -> Value {
+        let mut save: Value = serde_json::from_str(SAVE_V0).unwrap();
+        save["version"] = 1.into();
+        save["battle"] = serde_json::json!({
+            "battle": {
+                "engine": engine,
+                "current_message": "",
+                "turn_cooldown": 0.0,
+                "waiting_for_cooldown": false,
+                "battle_log": [],
+            },
+            "enemy_index": 0,
+        });
+        save
+    }
+
+    #[test]
+    fn v1_suspended_battle_is_rebuilt_from_the_replay() {
+        let catalog = CardCatalog::embedded();
+        let enemies = EnemyRoster::embedded().enemies.iter().take(2).map(|definition| definition.to_enemy(&catalog)).collect();
+        let mut engine =
+            BattleEngine::new(Player::new("Dani"), enemies, Deck::new(&catalog), StatusRegistry::embedded(), 50, 7);
+        auto_play(&mut engine, 20);
+        let save = v1_save_with_battle(serde_json::to_value(engine.replay()).unwrap());
+
+        let save = parse(&save.to_string()).unwrap();
+
+        let resumed = save.battle.expect("the suspended battle must survive the migration").battle.engine;
+        assert_eq!(serde_json::to_value(&resumed).unwrap(), serde_json::to_value(&engine).unwrap());
+    }
+
+    #[test]
+    fn unreadable_v1_battle_is_dropped_but_the_save_loads() {
+        let save = v1_save_with_battle(serde_json::json!({ "seed": "not a replay" }));
+
+        let save = parse(&save.to_string()).unwrap();
+
+        assert!(save.battle.is_none());
+        assert_eq!(save.player_name, "Dani");
+    }
+
+    #[test]
+    fn replay_is_kept_per_slot_and_deleted_with_it() {
+        let dir = TempDir::new("replay");
+        let backend = dir.backend();
+        let catalog = CardCatalog::embedded();
+        let enemies = EnemyRoster::embedded().enemies.iter().take(1).map(|definition| definition.to_enemy(&catalog)).collect();
+        let mut engine =
+            BattleEngine::new(Player::new("Dani"), enemies, Deck::new(&catalog), StatusRegistry::embedded(), 50, 3);
+        auto_play(&mut engine, 10);
+
+        engine.replay().save(&backend, &replay_key(1)).unwrap();
+
+        assert!(BattleReplay::load(&backend, &replay_key(0)).unwrap().is_none());
+        let replay = BattleReplay::load(&backend, &replay_key(1)).unwrap().unwrap();
+        assert_eq!(replay.actions, engine.replay().actions);
+        delete_slot(&backend, 1).unwrap();
+        assert!(dir.files().is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+use std::io;
+
+/// Onde os saves ficam guardados: cada save é um texto identificado por uma chave (`slot_1.json`, ...)
+pub trait SaveBackend {
+    /// Conteúdo da chave, ou `None` se ela não existir
+    fn read(&self, key: &str) -> io::Result<Option<String>>;
+    /// Grava a chave por inteiro; uma falha no meio não pode deixar o save pela metade
+    fn write(&self, key: &str, contents: &str) -> io::Result<()>;
+    /// Apaga a chave; apagar uma chave que não existe não é erro
+    fn remove(&self, key: &str) -> io::Result<()>;
+    /// Nome da chave nas mensagens de erro
+    fn describe(&self, key: &str) -> String;
+
+    fn exists(&self, key: &str) -> bool {
+        matches!(self.read(key), Ok(Some(_)))
+    }
+
+    fn rename(&self, from: &str, to: &str) -> io::Result<()> {
+        let contents = self
+            .read(from)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} not found", self.describe(from))))?;
+        self.write(to, &contents)?;
+        self.remove(from)
+    }
+}
+
+/// Backend da plataforma: arquivos na pasta de dados do usuário no desktop, localStorage no navegador
+#[cfg(not(target_arch = "wasm32"))]
+pub fn platform_backend() -> FileBackend {
+    FileBackend::new(FileBackend::default_dir())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn platform_backend() -> LocalStorageBackend {
+    LocalStorageBackend
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use file::FileBackend;
+
+#[cfg(target_arch = "wasm32")]
+pub use local_storage::LocalStorageBackend;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod file {
+    use super::SaveBackend;
+    use crate::config::config::*;
+    use std::fs::{self, File};
+    use std::io::{self, Write};
+    use std::path::PathBuf;
+
+    /// Cada chave é um arquivo dentro de `dir`
+    pub struct FileBackend {
+        dir: PathBuf,
+    }
+
+    impl FileBackend {
+        pub fn new(dir: PathBuf) -> Self {
+            Self { dir }
+        }
+
+        /// Pasta dos saves nos dados do usuário da plataforma; sem ela, a pasta atual
+        pub fn default_dir() -> PathBuf {
+            dirs::data_dir().map_or_else(|| PathBuf::from("."), |data_dir| data_dir.join(SAVE_DIR_NAME))
+        }
+
+        fn path(&self, key: &str) -> PathBuf {
+            self.dir.join(key)
+        }
+    }
+
+    impl SaveBackend for FileBackend {
+        fn read(&self, key: &str) -> io::Result<Option<String>> {
+            match fs::read_to_string(self.path(key)) {
+                Ok(contents) => Ok(Some(contents)),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// Escreve num temporário e troca pelo original, para nunca deixar o arquivo pela metade
+        fn write(&self, key: &str, contents: &str) -> io::Result<()> {
+            fs::create_dir_all(&self.dir)?;
+            let path = self.path(key);
+            let temp = path.with_extension("json.tmp");
+            let mut file = File::create(&temp)?;
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()?;
+            drop(file);
+            fs::rename(&temp, &path)
+        }
+
+        fn remove(&self, key: &str) -> io::Result<()> {
+            match fs::remove_file(self.path(key)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        }
+
+        fn describe(&self, key: &str) -> String {
+            self.path(key).display().to_string()
+        }
+
+        fn exists(&self, key: &str) -> bool {
+            self.path(key).exists()
+        }
+
+        fn rename(&self, from: &str, to: &str) -> io::Result<()> {
+            fs::rename(self.path(from), self.path(to))
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod local_storage {
+    use super::SaveBackend;
+    use crate::config::config::*;
+    use std::io;
+    use wasm_bindgen::JsValue;
+    use web_sys::Storage;
+
+    /// Cada chave vira um item do localStorage da página, prefixado com o nome do jogo
+    pub struct LocalStorageBackend;
+
+    fn to_io_error(error: JsValue) -> io::Error {
+        io::Error::other(error.as_string().unwrap_or_else(|| format!("{:?}", error)))
+    }
+
+    impl LocalStorageBackend {
+        fn storage(&self) -> io::Result<Storage> {
+            web_sys::window()
+                .ok_or_else(|| io::Error::other("no browser window"))?
+                .local_storage()
+                .map_err(to_io_error)?
+                .ok_or_else(|| io::Error::other("localStorage is not available"))
+        }
+
+        fn item(&self, key: &str) -> String {
+            format!("{}/{}", SAVE_DIR_NAME, key)
+        }
+    }
+
+    impl SaveBackend for LocalStorageBackend {
+        fn read(&self, key: &str) -> io::Result<Option<String>> {
+            self.storage()?.get_item(&self.item(key)).map_err(to_io_error)
+        }
+
+        /// O `setItem` troca o valor de uma vez, ou falha sem mexer no anterior (ex.: cota estourada)
+        fn write(&self, key: &str, contents: &str) -> io::Result<()> {
+            self.storage()?.set_item(&self.item(key), contents).map_err(to_io_error)
+        }
+
+        fn remove(&self, key: &str) -> io::Result<()> {
+            self.storage()?.remove_item(&self.item(key)).map_err(to_io_error)
+        }
+
+        fn describe(&self, key: &str) -> String {
+            format!("localStorage[{}]", self.item(key))
+        }
+    }
+}
@@ -1,38 +1,296 @@
-use crate::player::Player;
-use crate::enemy::Enemy;
-use crate::deck::Deck;
-use crate::gameturn::GameTurn;
+use crate::battle_engine::{BattleEngine, BattleEvent, BattleOutcome, Combatant};
+use crate::config::config::PHASE_TRANSITION_DURATION;
+use crate::effects::StatusEffect;
 
 use crate::state::damage_particle::DamageParticle;
 use macroquad::prelude::*;
+use ::rand::SeedableRng;
+use ::rand::rngs::StdRng;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BattleLogEntry {
     pub message: String,
+    #[serde(with = "color_format")]
     pub color: Color,
     pub timestamp: f32,
 }
 
+/// `Color` do macroquad não implementa serde; vai para o save como [r, g, b, a]
+mod color_format {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        [color.r, color.g, color.b, color.a].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let [r, g, b, a] = <[f32; 4]>::deserialize(deserializer)?;
+        Ok(Color::new(r, g, b, a))
+    }
+}
+
+/// Batalha em andamento. Só o motor, a mensagem, a espera do turno e o log vão para o save;
+/// animações e partículas recomeçam do zero ao retomar.
+#[derive(Serialize, Deserialize)]
 pub struct BattleState {
-    pub player: Player,
-    pub enemy: Enemy,
-    pub deck: Deck,
-    pub turn: GameTurn,
+    pub engine: BattleEngine,
     pub current_message: String,
+    #[serde(skip)]
     pub music_started: bool,
     pub turn_cooldown: f32,
     pub waiting_for_cooldown: bool,
+    #[serde(skip)]
     pub selected_card_index: Option<usize>,
+    #[serde(skip)]
     pub card_animation_timer: f32,
+    #[serde(skip)]
     pub enemy_shake_timer: f32,
+    /// Inimigo que treme ao levar dano
+    #[serde(skip)]
+    pub shaken_enemy: usize,
+    #[serde(skip)]
     pub damage_particles: Vec<DamageParticle>,
     pub battle_log: Vec<BattleLogEntry>,
+    #[serde(skip)]
     pub log_scroll_offset: f32,
+    #[serde(skip)]
     pub slow_motion_timer: f32,
+    #[serde(skip)]
     pub is_final_blow: bool,
+    /// Nome da fase de chefe que acabou de começar, mostrado por `phase_banner_timer` segundos
+    #[serde(skip)]
+    pub phase_banner: Option<String>,
+    #[serde(skip)]
+    pub phase_banner_timer: f32,
+    /// Sorteios puramente visuais, separados do motor para não alterar a luta
+    #[serde(skip, default = "StdRng::from_entropy")]
+    pub effects_rng: StdRng,
 }
 
 impl BattleState {
+    pub fn new(engine: BattleEngine) -> Self {
+        let effects_rng = StdRng::seed_from_u64(engine.seed());
+        Self {
+            engine,
+            current_message: "The battle has begun!".to_string(),
+            music_started: false,
+            turn_cooldown: 0.0,
+            waiting_for_cooldown: false,
+            selected_card_index: None,
+            card_animation_timer: 0.0,
+            enemy_shake_timer: 0.0,
+            shaken_enemy: 0,
+            damage_particles: Vec::new(),
+            battle_log: Vec::new(),
+            log_scroll_offset: 0.0,
+            slow_motion_timer: 0.0,
+            is_final_blow: false,
+            phase_banner: None,
+            phase_banner_timer: 0.0,
+            effects_rng,
+        }
+    }
+
+    /// Avança as animações de tremor, da carta jogada e das partículas de dano
+    pub fn update_effects(&mut self, dt: f32) {
+        self.enemy_shake_timer = (self.enemy_shake_timer - dt).max(0.0);
+        self.card_animation_timer = (self.card_animation_timer - dt).max(0.0);
+        for particle in &mut self.damage_particles {
+            particle.update(dt);
+        }
+        self.damage_particles.retain(|p| p.is_alive());
+    }
+
+    /// Conta o tempo do aviso de troca de fase do chefe
+    pub fn update_phase_banner(&mut self, dt: f32) {
+        self.phase_banner_timer = (self.phase_banner_timer - dt).max(0.0);
+        if self.phase_banner_timer <= 0.0 {
+            self.phase_banner = None;
+        }
+    }
+
+    fn combatant_name(&self, combatant: Combatant) -> String {
+        match combatant {
+            Combatant::Player => "Player".to_string(),
+            Combatant::Enemy(index) => self.engine.enemies[index].name.clone(),
+        }
+    }
+
+    fn status_name(&self, effect: StatusEffect) -> String {
+        self.engine
+            .statuses()
+            .get(effect)
+            .map_or_else(|| format!("{:?}", effect), |definition| definition.name.clone())
+    }
+
+    /// Traduz os eventos do motor em mensagens na tela e no log de batalha
+    pub fn record_events(&mut self, events: &[BattleEvent]) {
+        let mut card_name = String::new();
+        let mut source: Option<Combatant> = None;
+        let mut direct_damage: Option<u32> = None;
+
+        for event in events {
+            match event {
+                BattleEvent::CardPlayed { actor, card } => {
+                    card_name = card.name.clone();
+                    source = Some(*actor);
+                    direct_damage = (!card.card_type.triggers_strike()).then_some(0);
+                    let actor_name = self.combatant_name(*actor);
+                    self.add_card_log(&actor_name, &card.name);
+                }
+                BattleEvent::AttackUp { actor, amount } => {
+                    self.current_message = match actor {
+                        Combatant::Player => format!("Você usou {} e aumentou o ataque em {}!", card_name, amount),
+                        Combatant::Enemy(_) => format!("{} increased attack by {}!", self.combatant_name(*actor), amount),
+                    };
+                }
+                BattleEvent::DefenseUp { actor, amount } => {
+                    self.current_message = match actor {
+                        Combatant::Player => format!("Você usou {} e aumentou a defesa em {}!", card_name, amount),
+                        Combatant::Enemy(_) => format!("{} increased defense by {}!", self.combatant_name(*actor), amount),
+                    };
+                }
+                BattleEvent::BuffExpired { target, stat, amount } => {
+                    let target_name = self.combatant_name(*target);
+                    self.add_log(format!("{}'s +{} {:?} wore off", target_name, amount, stat), GRAY);
+                }
+                BattleEvent::Healed { target, amount } => {
+                    self.current_message = match target {
+                        Combatant::Player => format!("Você usou {} e se curou em {}!", card_name, amount),
+                        Combatant::Enemy(_) => format!("{} healed {}!", self.combatant_name(*target), amount),
+                    };
+                    let target_name = self.combatant_name(*target);
+                    self.add_heal_log(&target_name, *amount);
+                }
+                BattleEvent::StatusApplied { target, effect, duration } => {
+                    let status_name = self.status_name(*effect);
+                    self.current_message = match (source, target, effect) {
+                        (Some(Combatant::Player), Combatant::Enemy(_), StatusEffect::Poison) => format!("Você usou {} e envenenou o inimigo!", card_name),
+                        (Some(Combatant::Player), Combatant::Enemy(_), StatusEffect::Burn) => format!("Você usou {} e queimou! 🔥", card_name),
+                        (Some(Combatant::Player), _, _) => format!("Você usou {} e aplicou {}!", card_name, status_name),
+                        (_, Combatant::Player, StatusEffect::Poison) => "Enemy applied poison!".to_string(),
+                        (_, Combatant::Player, StatusEffect::Burn) => "Enemy burned! 🔥".to_string(),
+                        (_, target, _) => format!("{} agora está com {}!", self.combatant_name(*target), status_name),
+                    };
+                    let target_name = self.combatant_name(*target);
+                    self.add_status_log(&target_name, &status_name, *duration);
+                }
+                BattleEvent::Damage { attacker, target, damage, actual, blocked } => {
+                    let attacker_name = self.combatant_name(*attacker);
+                    let target_name = self.combatant_name(*target);
+                    if *blocked > 0 {
+                        self.add_log(format!("🛡️ {}'s shield absorbed {} damage", target_name, blocked), SKYBLUE);
+                    }
+                    self.add_damage_log(&attacker_name, &target_name, damage - blocked, *actual);
+
+                    if let Some(total) = direct_damage.as_mut() {
+                        *total += actual;
+                        self.current_message = match attacker {
+                            Combatant::Player => format!("Você usou {} e causou {} de dano!", card_name, total),
+                            Combatant::Enemy(_) => format!("{} used {} and dealt {} damage!", self.combatant_name(*attacker), card_name, total),
+                        };
+                    }
+                }
+                BattleEvent::ShieldUp { actor, amount } => {
+                    self.current_message = match actor {
+                        Combatant::Player => format!("Você usou {} e ergueu um escudo de {}!", card_name, amount),
+                        Combatant::Enemy(_) => format!("{} raised a {} shield!", self.combatant_name(*actor), amount),
+                    };
+                    let actor_name = self.combatant_name(*actor);
+                    self.add_log(format!("🛡️ {} raised a shield of {}", actor_name, amount), SKYBLUE);
+                }
+                BattleEvent::ShieldExpired { target, amount } => {
+                    let target_name = self.combatant_name(*target);
+                    self.add_log(format!("{}'s shield faded ({} left)", target_name, amount), GRAY);
+                }
+                BattleEvent::StatusTick { target, effect, damage, healed } => {
+                    let target_name = self.combatant_name(*target);
+                    let status_name = self.status_name(*effect);
+                    if *damage > 0 {
+                        self.add_log(format!("{} took {} damage from {}", target_name, damage, status_name), PURPLE);
+                    }
+                    if *healed > 0 {
+                        self.add_log(format!("{} recovered {} health from {}", target_name, healed, status_name), GREEN);
+                    }
+                }
+                BattleEvent::StatusExpired { target, effect } => {
+                    let target_name = self.combatant_name(*target);
+                    let status_name = self.status_name(*effect);
+                    self.add_log(format!("{} on {} ended", status_name, target_name), GRAY);
+                }
+                BattleEvent::Stunned(actor) => {
+                    self.current_message = match actor {
+                        Combatant::Player => "💫 Você está atordoado e perde o turno!".to_string(),
+                        Combatant::Enemy(_) => format!("💫 {} is stunned and skips the turn!", self.combatant_name(*actor)),
+                    };
+                    let actor_name = self.combatant_name(*actor);
+                    self.add_log(format!("💫 {} is stunned", actor_name), PURPLE);
+                }
+                BattleEvent::NoCards(Combatant::Player) => {
+                    self.current_message = "🃏 Sem cartas! Passando turno...".to_string();
+                }
+                BattleEvent::NoCards(enemy) => {
+                    self.current_message = format!("🃏 {} has no cards! Turn skipped.", self.combatant_name(*enemy));
+                }
+                BattleEvent::Reshuffled { cards } => {
+                    self.add_log(format!("🔀 Discard pile reshuffled into the draw pile ({} cards)", cards), SKYBLUE);
+                }
+                BattleEvent::CardExhausted(card) => {
+                    self.add_log(format!("{} was exhausted", card.name), GRAY);
+                }
+                BattleEvent::OutOfEnergy => {
+                    self.current_message = "⚡ Sem energia! Fim do turno.".to_string();
+                }
+                BattleEvent::TurnStarted(actor) => {
+                    let actor_name = match actor {
+                        Combatant::Enemy(_) if self.engine.living_enemies().count() > 1 => "Enemies".to_string(),
+                        _ => self.combatant_name(*actor),
+                    };
+                    self.add_turn_log(&actor_name);
+                }
+                BattleEvent::PhaseChanged { enemy, phase, name } => {
+                    // O que vem depois da troca de fase não é efeito de carta
+                    source = None;
+                    card_name.clear();
+                    let enemy_name = self.engine.enemies[*enemy].name.clone();
+                    self.current_message = format!("⚠️ {} entrou na fase: {}!", enemy_name, name);
+                    self.add_log(format!("⚠️ {} entered phase {}: {}", enemy_name, phase, name), ORANGE);
+                    let (new_tactics, new_cards) = self.engine.enemies[*enemy].phases.get(phase - 1)
+                        .map_or((false, false), |details| (details.ai.is_some(), details.card_pool.is_some()));
+                    if new_tactics {
+                        self.add_log(format!("🧠 {} changed tactics", enemy_name), ORANGE);
+                    }
+                    if new_cards {
+                        self.add_log(format!("🃏 {} drew a new hand", enemy_name), ORANGE);
+                    }
+                    self.phase_banner = Some(format!("⚠️ {} ⚠️", name.to_uppercase()));
+                    self.phase_banner_timer = PHASE_TRANSITION_DURATION;
+                }
+                BattleEvent::Summoned { summoner, enemy } => {
+                    let summoner_name = self.engine.enemies[*summoner].name.clone();
+                    let minion_name = self.engine.enemies[*enemy].name.clone();
+                    self.current_message = format!("👥 {} chamou {}!", summoner_name, minion_name);
+                    self.add_log(format!("👥 {} summoned {}", summoner_name, minion_name), ORANGE);
+                }
+                BattleEvent::TargetSelected(enemy) => {
+                    self.current_message = format!("🎯 Alvo: {}", self.engine.enemies[*enemy].name);
+                }
+                BattleEvent::EnemyDefeated(enemy) => {
+                    let enemy_name = self.engine.enemies[*enemy].name.clone();
+                    self.add_log(format!("☠️ {} was defeated!", enemy_name), GOLD);
+                }
+                BattleEvent::BattleEnded(BattleOutcome::Draw) => {
+                    self.current_message = "⏰ TIME'S UP! Turns have ended!".to_string();
+                    self.add_battle_end_log("Empate");
+                }
+                BattleEvent::BattleEnded(_) => {
+                    self.current_message = "💀 GOLPE FINAL! 💀".to_string();
+                }
+            }
+        }
+    }
+
     pub fn add_log(&mut self, message: String, color: Color) {
         let entry = BattleLogEntry {
             message,
@@ -79,9 +337,12 @@ impl BattleState {
         self.add_log(message, WHITE);
     }
     
-    pub fn add_battle_start_log(&mut self, enemy_name: &str) {
+    pub fn add_battle_start_log(&mut self) {
+        let enemy_names: Vec<&str> = self.engine.enemies.iter().map(|enemy| enemy.name.as_str()).collect();
+        let facing = format!("Facing: {}", enemy_names.join(", "));
         self.add_log("BATTLE STARTED!".to_string(), RED);
-        self.add_log(format!("Facing: {}", enemy_name), ORANGE);
+        self.add_log(facing, ORANGE);
+        self.add_log(format!("Seed: {}", self.engine.seed()), GRAY);
         self.add_log("Good luck, warrior!".to_string(), LIME);
     }
     
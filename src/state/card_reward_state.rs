@@ -0,0 +1,44 @@
+use crate::deck::Card;
+
+/// Escolha de uma carta entre as sorteadas após uma vitória
+pub struct CardRewardState {
+    pub choices: Vec<Card>,
+    pub selected_index: usize,
+    pub subtitle: String,
+    /// A carta vai para o baralho da run em vez da coleção
+    pub in_run: bool,
+}
+
+impl CardRewardState {
+    pub fn new(choices: Vec<Card>, enemy_name: &str) -> Self {
+        Self {
+            choices,
+            selected_index: 0,
+            subtitle: format!("{} defeated! Pick one card to add to your collection.", enemy_name),
+            in_run: false,
+        }
+    }
+
+    pub fn for_run(choices: Vec<Card>, subtitle: &str) -> Self {
+        Self {
+            choices,
+            selected_index: 0,
+            subtitle: subtitle.to_string(),
+            in_run: true,
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected_index + 1 < self.choices.len() {
+            self.selected_index += 1;
+        }
+    }
+
+    pub fn selected_card(&self) -> Option<&Card> {
+        self.choices.get(self.selected_index)
+    }
+}
@@ -1,4 +1,5 @@
 use macroquad::prelude::*;
+use ::rand::Rng;
 
 #[derive(Clone)]
 pub struct DamageParticle {
@@ -12,9 +13,7 @@ pub struct DamageParticle {
 }
 
 impl DamageParticle {
-    pub fn new(x: f32, y: f32, damage: u32) -> Self {
-        use ::rand::Rng;
-        let mut rng = ::rand::thread_rng();
+    pub fn new<R: Rng>(x: f32, y: f32, damage: u32, rng: &mut R) -> Self {
         Self {
             x,
             y,
@@ -0,0 +1,62 @@
+use crate::card_catalog::CardCatalog;
+use crate::collection::CardCollection;
+
+/// Edição do baralho sobre uma cópia da coleção; só é aplicada ao salvar
+pub struct DeckEditorState {
+    pub collection: CardCollection,
+    /// Ids das cartas que o jogador possui, na ordem do catálogo
+    pub card_ids: Vec<String>,
+    pub selected_index: usize,
+    pub message: Option<String>,
+}
+
+impl DeckEditorState {
+    pub fn new(collection: CardCollection, catalog: &CardCatalog) -> Self {
+        let card_ids = catalog
+            .cards
+            .iter()
+            .filter(|definition| collection.owned_copies(&definition.id) > 0)
+            .map(|definition| definition.id.clone())
+            .collect();
+
+        Self {
+            collection,
+            card_ids,
+            selected_index: 0,
+            message: None,
+        }
+    }
+
+    pub fn selected_card_id(&self) -> Option<&str> {
+        self.card_ids.get(self.selected_index).map(String::as_str)
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected_index + 1 < self.card_ids.len() {
+            self.selected_index += 1;
+        }
+    }
+
+    pub fn add_selected(&mut self, catalog: &CardCatalog) {
+        let Some(id) = self.selected_card_id().map(str::to_string) else {
+            return;
+        };
+        self.message = if self.collection.add_to_deck(catalog, &id) {
+            None
+        } else {
+            Some("No more copies of this card can be added".to_string())
+        };
+    }
+
+    pub fn remove_selected(&mut self) {
+        let Some(id) = self.selected_card_id().map(str::to_string) else {
+            return;
+        };
+        self.collection.remove_from_deck(&id);
+        self.message = None;
+    }
+}
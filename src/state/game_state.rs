@@ -1,27 +1,53 @@
 use crate::state::battle_state::BattleState;
 use crate::state::menu::MenuSelection;
-use crate::deck::{CardTextureManager, CardType, Deck, Hand};
+use crate::battle_engine::{BattleAction, BattleEngine, BattleEvent, BattleOutcome, Combatant};
+use crate::card_catalog::CardCatalog;
+use crate::collection::CardCollection;
+use crate::deck::{CardTextureManager, Deck, EnemyCardChances, Hand};
+use crate::enemy_roster::{EnemyDefinition, EnemyRoster};
+use crate::status_registry::StatusRegistry;
+use crate::boss::BossPhaseDefinition;
 use crate::enemy::Enemy;
+use crate::enemy_ai::EnemyAiKind;
 use crate::player::Player;
-use crate::effects::StatusEffect;
 use crate::entity::Entity;
-use crate::gameturn::GameTurn;
 use crate::state::damage_particle::DamageParticle;
+use crate::state::replay_state::ReplayState;
+use crate::state::deck_editor_state::DeckEditorState;
+use crate::state::card_reward_state::CardRewardState;
+use crate::rewards::{gold_reward, roll_card_rewards, roll_cards};
+use crate::run::{NodeKind, RunState};
+use crate::state::run_map_state::RunMapState;
+use crate::state::shop_state::ShopState;
+use crate::state::profile_state::ProfileState;
+use crate::state::ui::deck_editor::DeckEditorButton;
+use crate::state::ui::shop::ShopClick;
+use crate::state::ui::components::enemy_sprite_rect;
+use crate::replay::BattleReplay;
+use crate::error::GameError;
+use crate::save::{self, SAVE_VERSION};
+use crate::save_backend::{SaveBackend, platform_backend};
 use crate::config::config::*;
 use macroquad::prelude::*;
+use ::rand::SeedableRng;
+use ::rand::rngs::StdRng;
 
-use ::rand::thread_rng;
-use ::rand::prelude::SliceRandom;
 use serde::{Deserialize, Serialize};
-use std::fs;
-use std::path::Path;
 
 pub enum AppState {
+    ProfileSelect(ProfileState),
     Menu,
     EnemySelection,
-    Battle(BattleState),
+    Battle(Box<BattleState>),
     GameOver,
     SoundSettings,
+    Replay(Box<ReplayState>),
+    DeckEditor(DeckEditorState),
+    CardReward(CardRewardState),
+    RunMap(RunMapState),
+    Shop(ShopState),
+    /// Pergunta se a batalha suspensa em `GameState::suspended_battle` deve ser retomada
+    ResumeBattle,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -41,14 +67,44 @@ pub struct EnemyInfo {
     pub is_defeated: bool,
     pub emoji: Option<String>,
     pub image: Option<String>,
+    #[serde(default)]
+    pub attack_sound: Option<String>,
+    #[serde(default)]
+    pub card_chances: EnemyCardChances,
+    #[serde(default)]
+    pub ai: EnemyAiKind,
+    #[serde(default)]
+    pub phases: Vec<BossPhaseDefinition>,
+    #[serde(default)]
+    pub escort: Vec<u32>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct SaveData {
+    /// Versão do formato; saves antigos passam pelas migrações de `save` ao carregar
+    #[serde(default)]
+    pub version: u32,
     pub enemies: Vec<EnemyInfo>,
     pub persistent_player: Option<PlayerSaveData>,
     pub player_name: String,
     pub sound_settings: SoundSettings,
+    #[serde(default)]
+    pub collection: CardCollection,
+    #[serde(default)]
+    pub run: Option<RunState>,
+    #[serde(default)]
+    pub gold: u32,
+    /// Batalha interrompida ao fechar o jogo
+    #[serde(default)]
+    pub battle: Option<SuspendedBattle>,
+}
+
+/// Batalha salva ao fechar o jogo no meio da luta, retomada no mesmo turno
+#[derive(Serialize, Deserialize)]
+pub struct SuspendedBattle {
+    pub battle: BattleState,
+    /// Inimigo escolhido na seleção; nas batalhas da run vale a parada ativa da run
+    pub enemy_index: usize,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -56,6 +112,7 @@ pub struct PlayerSaveData {
     pub name: String,
     pub health: u32,
     pub max_health: u32,
+    /// Ataque e defesa base, sem os bônus de carta da batalha (a partir da versão 1 do save)
     pub attack: u32,
     pub defense: u32,
     pub level: u32,
@@ -63,6 +120,33 @@ pub struct PlayerSaveData {
     pub experience_to_next_level: u32,
 }
 
+impl PlayerSaveData {
+    pub fn from_player(player: &Player) -> Self {
+        Self {
+            name: player.name.clone(),
+            health: player.health,
+            max_health: player.max_health,
+            attack: player.attack,
+            defense: player.defense,
+            level: player.level,
+            experience: player.experience,
+            experience_to_next_level: player.experience_to_next_level,
+        }
+    }
+
+    pub fn to_player(&self) -> Player {
+        let mut player = Player::new(&self.name);
+        player.health = self.health.min(self.max_health);
+        player.max_health = self.max_health;
+        player.attack = self.attack;
+        player.defense = self.defense;
+        player.level = self.level;
+        player.experience = self.experience;
+        player.experience_to_next_level = self.experience_to_next_level;
+        player
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SoundSettings {
     pub music_volume: f32,
@@ -76,6 +160,10 @@ pub struct GameState {
     pub selection: MenuSelection,
     pub winner: Option<String>,
     pub card_textures: CardTextureManager,
+    pub card_catalog: CardCatalog,
+    pub enemy_roster: EnemyRoster,
+    pub status_registry: StatusRegistry,
+    pub collection: CardCollection,
     pub enemies: Vec<EnemyInfo>,
     pub selected_enemy_index: usize,
     pub persistent_player: Option<Player>, 
@@ -90,91 +178,94 @@ pub struct GameState {
     pub music_enabled: bool,              
     pub sfx_enabled: bool,                
     pub music_started: bool,              
+    pub seed_input: String,
+    pub is_editing_seed: bool,
+    pub last_battle_seed: Option<u64>,
+    pub last_replay: Option<BattleReplay>,
+    /// Run do modo roguelike em andamento
+    pub run: Option<RunState>,
+    /// Ouro ganho nas vitórias e gasto na loja
+    pub gold: u32,
+    /// Erro ao carregar o save, mostrado no menu
+    pub save_error: Option<GameError>,
+    /// O save do slot não carregou e ainda há checkpoint para restaurar: nada é gravado até o Continue,
+    /// para um perfil vazio não tomar o lugar do save nem empurrar os backups para fora da fila
+    pub restore_pending: bool,
+    /// Slot de save do perfil em uso
+    pub slot: usize,
+    /// Batalha carregada do save esperando o jogador decidir se retoma
+    pub suspended_battle: Option<SuspendedBattle>,
 
 }
 
-fn get_default_enemies() -> Vec<EnemyInfo> {
-    vec![
-        EnemyInfo {
-            id: 1,
-            name: "Esqueleto Bombado".to_string(),
-            base_health: 80,
-            base_attack: 8,
-            base_defense: 5,
-            health: 80,
-            max_health: 80,
-            attack: 8,
-            defense: 5,
-            level: 1,
-            times_defeated: 0,
-            is_unlocked: true, 
-            is_defeated: false,
-            emoji: Some("💀".to_string()),
-            image: Some("assets/enemies/skeleton.png".to_string()),
-        },
-        EnemyInfo {
-            id: 2,
-            name: "Zumbi Influencer".to_string(),
-            base_health: 140,
-            base_attack: 35,
-            base_defense: 16,
-            health: 140,
-            max_health: 140,
-            attack: 25,
-            defense: 16,
-            level: 1,
-            times_defeated: 0,
-            is_unlocked: false, 
-            is_defeated: false,
-            emoji: Some("🧟".to_string()),
-            image: Some("assets/enemies/zombie.png".to_string()),
-        },
-        EnemyInfo {
-            id: 3,
-            name: "Dragoberto".to_string(),
-            base_health: 220,
-            base_attack: 32,
-            base_defense: 30,
-            health: 220,
-            max_health: 220,
-            attack: 32,
-            defense: 30,
-            level: 3,
-            times_defeated: 0,
-            is_unlocked: false, 
-            is_defeated: false,
-            emoji: Some("🐲".to_string()),
-            image: Some("assets/enemies/dragon.png".to_string()),
-        },
-        EnemyInfo {
-            id: 4,
-            name: "Psicopapão".to_string(),
-            base_health: 520,
-            base_attack: 66,
-            base_defense: 12,
-            health: 520,
-            max_health: 520,
-            attack: 66,
-            defense: 12,
-            level: 1,
+impl EnemyInfo {
+    /// Inimigo no estado inicial, antes de qualquer progresso
+    pub fn from_definition(definition: &EnemyDefinition) -> Self {
+        let health = definition.health.unwrap_or(definition.base_health);
+        Self {
+            id: definition.id,
+            name: definition.name.clone(),
+            base_health: definition.base_health,
+            base_attack: definition.base_attack,
+            base_defense: definition.base_defense,
+            health,
+            max_health: health,
+            attack: definition.attack.unwrap_or(definition.base_attack),
+            defense: definition.defense.unwrap_or(definition.base_defense),
+            level: definition.level,
             times_defeated: 0,
-            is_unlocked: false, 
+            is_unlocked: false,
             is_defeated: false,
-            emoji: Some("👾".to_string()),
-            image: Some("assets/enemies/devourer.png".to_string()),
-        },
-    ]
+            emoji: definition.emoji.clone(),
+            image: Some(definition.image.clone()),
+            attack_sound: definition.attack_sound.clone(),
+            card_chances: definition.card_chances.clone(),
+            ai: definition.ai.clone(),
+            phases: definition.phases.clone(),
+            escort: definition.escort.clone(),
+        }
+    }
+
+    /// Atualiza os dados fixos a partir do roster, mantendo o progresso salvo
+    fn refresh_from_definition(&mut self, definition: &EnemyDefinition) {
+        self.name = definition.name.clone();
+        self.base_health = definition.base_health;
+        self.base_attack = definition.base_attack;
+        self.base_defense = definition.base_defense;
+        self.emoji = definition.emoji.clone();
+        self.image = Some(definition.image.clone());
+        self.attack_sound = definition.attack_sound.clone();
+        self.card_chances = definition.card_chances.clone();
+        self.ai = definition.ai.clone();
+        self.phases = definition.phases.clone();
+        self.escort = definition.escort.clone();
+    }
+}
+
+fn default_enemies(roster: &EnemyRoster) -> Vec<EnemyInfo> {
+    let mut enemies: Vec<EnemyInfo> = roster.enemies.iter().map(EnemyInfo::from_definition).collect();
+    if let Some(first) = enemies.first_mut() {
+        first.is_unlocked = true;
+    }
+    enemies
 }
 
 impl GameState {
     pub fn new() -> Self {
-        let enemies = get_default_enemies();
+        let enemy_roster = EnemyRoster::embedded();
+        let enemies = default_enemies(&enemy_roster);
+        let card_catalog = CardCatalog::embedded();
+        let collection = CardCollection::starter(&card_catalog);
 
-        let mut game_state = Self {
-            app_state: AppState::Menu,
+        Self {
+            app_state: AppState::ProfileSelect(ProfileState::new(None)),
             selection: MenuSelection::Start,
             winner: None,
             card_textures: CardTextureManager::new(),
+            card_catalog,
+            enemy_roster,
+            status_registry: StatusRegistry::embedded(),
+            collection,
             enemies,
             selected_enemy_index: 0,
             persistent_player: None,
@@ -189,18 +280,36 @@ impl GameState {
             music_enabled: true,
             sfx_enabled: true,
             music_started: false,
-        };
-        
-        game_state.load_progress();
-        
-        game_state
+            seed_input: String::new(),
+            is_editing_seed: false,
+            last_battle_seed: None,
+            last_replay: None,
+            run: None,
+            gold: 0,
+            save_error: None,
+            restore_pending: false,
+            slot: 0,
+            suspended_battle: None,
+        }
+    }
+
+    /// Carrega cartas, inimigos e efeitos de status dos arquivos de dados, substituindo as cópias embutidas
+    pub async fn load_game_data(&mut self) {
+        self.card_catalog = CardCatalog::load().await;
+        self.enemy_roster = EnemyRoster::load().await;
+        self.status_registry = StatusRegistry::load().await;
+        self.sync_enemies_with_roster();
     }
 
     pub async fn load_card_textures(&mut self) {
-        self.card_textures.load_all_textures().await;
+        self.card_textures.load_all_textures(&self.card_catalog, &self.enemy_roster).await;
     }
 
     pub fn update(&mut self) {
+        if is_quit_requested() {
+            self.quit();
+        }
+
         let current_width = screen_width();
         let current_height = screen_height();
         
@@ -212,9 +321,9 @@ impl GameState {
         
         let mut clicked_card_index = None;
         if let AppState::Battle(battle) = &self.app_state {
-            if battle.turn.player_turn() && !battle.waiting_for_cooldown {
+            if battle.engine.turn.player_turn() && !battle.waiting_for_cooldown {
                 let (mouse_x, mouse_y) = mouse_position();
-                clicked_card_index = self.get_clicked_card_index(mouse_x, mouse_y, &battle.player.hand);
+                clicked_card_index = self.get_clicked_card_index(mouse_x, mouse_y, &battle.engine.player.hand);
             }
         }
         if !self.music_started && self.music_enabled {
@@ -223,6 +332,62 @@ impl GameState {
         }
 
         match &mut self.app_state {
+            AppState::ProfileSelect(profiles) => {
+                if let Some(name) = profiles.new_name.as_mut() {
+                    while let Some(ch) = get_char_pressed() {
+                        if (ch.is_ascii_alphanumeric() || ch == ' ') && name.len() < MAX_PLAYER_NAME_LENGTH {
+                            name.push(ch);
+                        }
+                    }
+                    if is_key_pressed(KeyCode::Backspace) {
+                        name.pop();
+                    }
+                    if is_key_pressed(KeyCode::Escape) {
+                        profiles.new_name = None;
+                    } else if is_key_pressed(KeyCode::Enter) && !name.trim().is_empty() {
+                        let name = name.trim().to_string();
+                        let slot = profiles.selected_index;
+                        self.create_profile(slot, name);
+                    }
+                    return;
+                }
+
+                if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+                    profiles.select_previous();
+                }
+                if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+                    profiles.select_next();
+                }
+                if is_key_pressed(KeyCode::C) {
+                    profiles.copy_selected();
+                }
+                if is_key_pressed(KeyCode::Delete) {
+                    profiles.delete_selected();
+                }
+
+                let mut choose = is_key_pressed(KeyCode::Enter);
+                if is_mouse_button_pressed(MouseButton::Left) {
+                    let (mouse_x, mouse_y) = mouse_position();
+                    if let Some(slot) = crate::state::ui::profile_select::get_clicked_profile_slot(mouse_x, mouse_y, profiles.slots.len()) {
+                        if slot != profiles.selected_index {
+                            profiles.confirm_delete = false;
+                        }
+                        profiles.selected_index = slot;
+                        choose = true;
+                    }
+                }
+
+                if is_key_pressed(KeyCode::Escape) {
+                    std::process::exit(0);
+                } else if choose && profiles.is_selected_empty() {
+                    // Descarta as teclas que ficaram na fila antes de começar a digitar o nome
+                    while get_char_pressed().is_some() {}
+                    profiles.new_name = Some(String::new());
+                } else if choose {
+                    let slot = profiles.selected_index;
+                    self.open_profile(slot);
+                }
+            }
             AppState::Menu => {
                 if self.is_editing_name {
                     if is_key_pressed(KeyCode::Enter) {
@@ -268,18 +433,10 @@ impl GameState {
                     }
                 } else {
                     if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
-                        self.selection = match self.selection {
-                            MenuSelection::Start => MenuSelection::Start,
-                            MenuSelection::SoundSettings => MenuSelection::Start,
-                            MenuSelection::Quit => MenuSelection::SoundSettings,
-                        };
+                        self.selection = self.selection.previous();
                     }
                     if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
-                        self.selection = match self.selection {
-                            MenuSelection::Start => MenuSelection::SoundSettings,
-                            MenuSelection::SoundSettings => MenuSelection::Quit,
-                            MenuSelection::Quit => MenuSelection::Quit,
-                        };
+                        self.selection = self.selection.next();
                     }
                     if is_key_pressed(KeyCode::Tab) {
                         self.is_editing_name = true;
@@ -311,6 +468,33 @@ impl GameState {
                 }
             }
             AppState::EnemySelection => {
+                if self.is_editing_seed {
+                    if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Tab) {
+                        self.is_editing_seed = false;
+                    }
+                    if is_key_pressed(KeyCode::Escape) {
+                        self.is_editing_seed = false;
+                        self.seed_input.clear();
+                    }
+                    if is_key_pressed(KeyCode::Backspace) {
+                        self.seed_input.pop();
+                    }
+
+                    for (digit, key_code) in [
+                        KeyCode::Key0, KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4,
+                        KeyCode::Key5, KeyCode::Key6, KeyCode::Key7, KeyCode::Key8, KeyCode::Key9,
+                    ].into_iter().enumerate() {
+                        if is_key_pressed(key_code) && self.seed_input.len() < MAX_SEED_LENGTH {
+                            self.seed_input.push_str(&digit.to_string());
+                        }
+                    }
+                    return;
+                }
+
+                if is_key_pressed(KeyCode::Tab) {
+                    self.is_editing_seed = true;
+                }
+
                 if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A) {
                     if self.selected_enemy_index > 0 {
                         self.selected_enemy_index -= 1;
@@ -325,6 +509,16 @@ impl GameState {
                 if is_key_pressed(KeyCode::I) {
                     self.show_instructions = !self.show_instructions;
                 }
+
+                if is_key_pressed(KeyCode::E) {
+                    self.app_state = AppState::DeckEditor(DeckEditorState::new(self.collection.clone(), &self.card_catalog));
+                    return;
+                }
+
+                if is_key_pressed(KeyCode::S) {
+                    self.open_shop();
+                    return;
+                }
                 
                 if is_key_pressed(KeyCode::Enter) {
                     self.start_battle_with_selected_enemy();
@@ -370,299 +564,118 @@ impl GameState {
                 } else {
                     battle.log_scroll_offset = 0.0;
                 }
-                if battle.turn.turn_over() {
-                    battle.current_message = "⏰ TIME'S UP! Turns have ended!".to_string();
-                    battle.add_battle_end_log("Empate");
-                    self.winner = Some("Empate".to_string());
-                    self.on_battle_end("Playable turns have ended!");
-                    self.app_state = AppState::GameOver;
+
+                battle.update_phase_banner(get_frame_time());
+
+                if battle.is_final_blow {
+                    let frame_time = get_frame_time();
+                    battle.slow_motion_timer -= frame_time;
+                    
+                    for particle in &mut battle.damage_particles {
+                        particle.update(frame_time);
+                    }
+                    battle.damage_particles.retain(|p| p.is_alive());
+                    
+                    if battle.slow_motion_timer <= 0.0 || battle.slow_motion_timer > 2.0 {
+                        battle.slow_motion_timer = 0.0;
+                        battle.is_final_blow = false;
+                        let outcome = battle.engine.outcome();
+                        self.finish_battle(outcome);
+                    }
                     return;
                 }
-                if battle.turn.player_turn() {
-                    if battle.is_final_blow {
-                        let frame_time = get_frame_time();
-                        battle.slow_motion_timer -= frame_time;
-                        
-                        for particle in &mut battle.damage_particles {
-                            particle.update(frame_time);
-                        }
-                        battle.damage_particles.retain(|p| p.is_alive());
-                        
-                        if battle.slow_motion_timer <= 0.0 || battle.slow_motion_timer > 2.0 {
-                            battle.slow_motion_timer = 0.0;
-                            battle.is_final_blow = false;
-                            
-                            if battle.enemy.health <= 0 {
-                                battle.add_battle_end_log("Jogador");
-                                self.winner = Some("Jogador".to_string());
-                                self.on_battle_end("Player");
-                                self.app_state = AppState::GameOver;
-                                return;
-                            } else {
-                                battle.add_battle_end_log("Inimigo");
-                                self.winner = Some("Inimigo".to_string());
-                                self.on_battle_end("Enemy");
-                                self.app_state = AppState::GameOver;
-                                return;
-                            }
+
+                if let Some(BattleOutcome::Draw) = battle.engine.outcome() {
+                    self.finish_battle(Some(BattleOutcome::Draw));
+                    return;
+                }
+
+                if battle.waiting_for_cooldown {
+                    battle.turn_cooldown -= get_frame_time();
+                    battle.update_effects(get_frame_time());
+                    if battle.turn_cooldown <= 0.0 {
+                        battle.waiting_for_cooldown = false;
+                        // O jogador segue no turno enquanto alguma carta couber na energia,
+                        // e o turno dos inimigos só acaba depois que todos agirem
+                        if !battle.engine.player_can_act() && battle.engine.enemy_to_act().is_none() {
+                            let events = battle.engine.apply(BattleAction::EndTurn);
+                            battle.record_events(&events);
                         }
+                    }
+                } else if battle.engine.current_actor() == Combatant::Player {
+                    if !battle.engine.player_has_cards() {
+                        battle.record_events(&[BattleEvent::NoCards(Combatant::Player)]);
+                        battle.turn_cooldown = PLAYER_TURN_COOLDOWN;
+                        battle.waiting_for_cooldown = true;
                         return;
                     }
-                    
-                    if battle.player.hand.cards.is_empty() && battle.deck.cards.is_empty() {
-                        battle.current_message = "🃏 Sem cartas! Passando turno...".to_string();
+                    if battle.engine.is_stunned(Combatant::Player) {
                         battle.turn_cooldown = PLAYER_TURN_COOLDOWN;
                         battle.waiting_for_cooldown = true;
-                    } else if !battle.waiting_for_cooldown {
-                        let mut card_played = None;
-                        for i in 0..battle.player.hand.cards.len().min(5) {
-                            let key = match i {
-                                0 => KeyCode::Key1,
-                                1 => KeyCode::Key2,
-                                2 => KeyCode::Key3,
-                                3 => KeyCode::Key4,
-                                4 => KeyCode::Key5,
-                                _ => continue,
-                            };
-                            if is_key_pressed(key) {
-                                if let Some(card) = battle.player.hand.discard(i) {
-                                    battle.selected_card_index = Some(i);
-                                    card_played = Some(card);
-                                    break;
-                                }
-                            }
-                        }
-                        if is_mouse_button_pressed(MouseButton::Left) {
-                            if let Some(index) = clicked_card_index {
-                                if let Some(card) = battle.player.hand.discard(index) {
-                                    battle.selected_card_index = Some(index);
-                                    card_played = Some(card);
-                                }
-                            }
-                        }
-                        if let Some(card) = card_played {
-                            self.card_textures.play_card_use_sound();
-                            battle.card_animation_timer = 0.3;
-                            
-                            battle.add_card_log("Player", &card.name);
-                            
-                            match card.card_type {
-                                CardType::AttackBasic(damage) => {
-                                    battle.player.attack_up(damage);
-                                    battle.current_message = format!(
-                                        "Você usou {} e aumentou o ataque em {}!",
-                                        card.name, damage
-                                    );
-                                }
-                                CardType::AttackStrong(damage) => {
-                                    battle.player.attack_up(damage);
-                                    battle.current_message =
-                                        format!("Você usou {} e aumentou o ataque em {}!",
-                                        card.name, damage
-                                    );
-                                }
-                                CardType::Defense(defense) => {
-                                    battle.player.defense_up(defense);
-                                    battle.current_message = format!(
-                                        "Você usou {} e aumentou a defesa em {}!",
-                                        card.name, defense
-                                    );
-                                }
-                                CardType::Poison(_) => {
-                                    battle.enemy.status_effect(StatusEffect::Poison, STATUS_EFFECT_DURATION_POISON);
-                                    battle.current_message =
-                                        format!("Você usou {} e envenenou o inimigo!", card.name);
-                                }
-                                CardType::Heal(heal_percent) => {
-                                    let heal_amount = (battle.player.max_health as f32 * heal_percent) as u32;
-                                    battle.player.heal(heal_amount);
-                                    battle.current_message =
-                                        format!("Você usou {} e se curou em {}!", card.name, heal_amount);
-                                    battle.add_heal_log("Jogador", heal_amount);
-                                        
-                                }
-                                CardType::Burn(_) => {
-                                    battle.enemy.status_effect(StatusEffect::Burn, STATUS_EFFECT_DURATION_BURN);
-                                    battle.current_message =
-                                        format!("Você usou {} e queimou! 🔥", card.name);
-                                }
-                            }
-                            if !battle.deck.cards.is_empty() {
-                                battle.player.hand.draw_from_deck(&mut battle.deck, 1);
-                            }
-                            let damage_dealt = battle.player.attack;
-                            let enemy_health_before = battle.enemy.health;
-                            battle.enemy.damage(damage_dealt);
-                            let actual_damage = enemy_health_before - battle.enemy.health;
-                            
-                            let enemy_name = battle.enemy.name.clone();
-                            battle.add_damage_log("Player", &enemy_name, damage_dealt, actual_damage);
-                            
-                            battle.enemy.apply_status_effects();
-                            
-                            if battle.enemy.health <= 0 {
-                                battle.is_final_blow = true;
-                                battle.slow_motion_timer = 1.0;
-                                battle.current_message = "💀 GOLPE FINAL! 💀".to_string();
-                                battle.waiting_for_cooldown = true;
-                            } else {
-                                battle.enemy_shake_timer = ENEMY_SHAKE_DURATION;
-                                let enemy_x = screen_width() / 2.0;
-                                let enemy_y = screen_height() / 2.0 - 50.0;
-                                battle.damage_particles.push(DamageParticle::new(
-                                    enemy_x,
-                                    enemy_y,
-                                    damage_dealt,
-                                ));
-                                battle.turn_cooldown = PLAYER_TURN_COOLDOWN;
-                                battle.waiting_for_cooldown = true;
-                            }
-                        }
-                    } else {
-                        battle.turn_cooldown -= get_frame_time();
-                        battle.enemy_shake_timer =
-                            (battle.enemy_shake_timer - get_frame_time()).max(0.0);
-                        battle.card_animation_timer =
-                            (battle.card_animation_timer - get_frame_time()).max(0.0);
-                        for particle in &mut battle.damage_particles {
-                            particle.update(get_frame_time());
-                        }
-                        battle.damage_particles.retain(|p| p.is_alive());
-                        if battle.turn_cooldown <= 0.0 {
-                            battle.waiting_for_cooldown = false;
-                            battle.turn.next_turn();
-                            
-                            let enemy_name = battle.enemy.name.clone();
-                            battle.add_turn_log(&enemy_name);
-                        }
+                        return;
                     }
-                } else {
-                    if battle.is_final_blow {
-                        let frame_time = get_frame_time();
-                        battle.slow_motion_timer -= frame_time;
-                        
-                        for particle in &mut battle.damage_particles {
-                            particle.update(frame_time);
+
+                    if battle.engine.living_enemies().count() > 1 {
+                        let mut new_target = None;
+                        if is_key_pressed(KeyCode::Tab) || is_key_pressed(KeyCode::Right) {
+                            new_target = battle.engine.cycle_target(true);
+                        } else if is_key_pressed(KeyCode::Left) {
+                            new_target = battle.engine.cycle_target(false);
+                        } else if is_mouse_button_pressed(MouseButton::Left) && clicked_card_index.is_none() {
+                            let (mouse_x, mouse_y) = mouse_position();
+                            let count = battle.engine.enemies.len();
+                            new_target = (0..count).find(|index| {
+                                battle.engine.is_alive(*index) && enemy_sprite_rect(*index, count).contains(vec2(mouse_x, mouse_y))
+                            });
                         }
-                        battle.damage_particles.retain(|p| p.is_alive());
-                        
-                        if battle.slow_motion_timer <= 0.0 || battle.slow_motion_timer > 2.0 {
-                            battle.slow_motion_timer = 0.0;
-                            battle.is_final_blow = false;
-                            
-                            if battle.enemy.health <= 0 {
-                                battle.add_battle_end_log("Player");
-                                self.winner = Some("Player".to_string());
-                                self.on_battle_end("Player");
-                                self.app_state = AppState::GameOver;
-                                return;
-                            } else {
-                                battle.add_battle_end_log("Enemy");
-                                self.winner = Some("Enemy".to_string());
-                                self.on_battle_end("Enemy");
-                                self.app_state = AppState::GameOver;
-                                return;
-                            }
+                        if let Some(index) = new_target {
+                            let events = battle.engine.apply(BattleAction::SelectTarget(index));
+                            battle.record_events(&events);
                         }
+                    }
+
+                    let end_turn_clicked = is_mouse_button_pressed(MouseButton::Left) && {
+                        let (mouse_x, mouse_y) = mouse_position();
+                        crate::state::ui::components::is_end_turn_button_clicked(mouse_x, mouse_y)
+                    };
+                    if is_key_pressed(KeyCode::E) || is_key_pressed(KeyCode::Space) || end_turn_clicked {
+                        let events = battle.engine.apply(BattleAction::EndTurn);
+                        battle.record_events(&events);
                         return;
                     }
-                    
-                    if !battle.waiting_for_cooldown {
-                        if let Some(card) = battle.deck.cards.choose(&mut thread_rng()).cloned() {
-                            let enemy_name = battle.enemy.name.to_lowercase();
-                            let sound_key = if enemy_name.contains("esqueleto") || enemy_name.contains("skeleton") {
-                                "skeleton"
-                            } else if enemy_name.contains("zumbi") || enemy_name.contains("zombie") {
-                                "zombie"
-                            } else if enemy_name.contains("drag") || enemy_name.contains("dragon") {
-                                "dragon"
-                            } else if enemy_name.contains("devourer") || enemy_name.contains("psicopapão") {
-                                "devourer"
-                            } else {
-                                "skeleton"
-                            };
-                            self.card_textures.play_enemy_sound(sound_key);
-                            match card.card_type {
-                                CardType::AttackBasic(attack) => {
-                                    battle.enemy.attack_up(attack);
-                                    battle.current_message =
-                                        format!("Enemy increased attack by {}!", attack);
-                                }
-                                CardType::AttackStrong(attack) => {
-                                    battle.enemy.attack_up(attack);
-                                    battle.current_message =
-                                        format!("Enemy increased attack by {}!", attack);
-                                }
-                                CardType::Defense(defense) => {
-                                    battle.enemy.defense_up(defense);
-                                    battle.current_message =
-                                        format!("Enemy increased defense by {}!", defense);
-                                }
-                                CardType::Poison(_) => {
-                                    battle.player.status_effect(StatusEffect::Poison, STATUS_EFFECT_DURATION_POISON);
-                                    battle.current_message = "Enemy applied poison!".to_string();
-                                }
-                                CardType::Heal(heal_percent) => {   
-                                    let heal_amount = (battle.enemy.max_health as f32 * heal_percent) as u32;
-                                    battle.enemy.heal(heal_amount);
-                                    battle.current_message = format!("Enemy healed {}!", heal_amount);
-                                }
-                                CardType::Burn(_) => {
-                                    battle.player.status_effect(StatusEffect::Burn, STATUS_EFFECT_DURATION_BURN);
-                                    battle.current_message =
-                                        format!("Enemy burned! 🔥");
-                                }
-                            }
-                            let damage_dealt = battle.enemy.attack;
-                            let player_health_before = battle.player.health;
-                            battle.player.damage(damage_dealt);
-                            let actual_damage = player_health_before - battle.player.health;
-                            
-                            let enemy_name = battle.enemy.name.clone();
-                            battle.add_damage_log(&enemy_name, "Player", damage_dealt, actual_damage);
-                            
-                            battle.player.apply_status_effects();
-                            
-                            if battle.player.health <= 0 {
-                                battle.is_final_blow = true;
-                                battle.slow_motion_timer = 1.0;
-                                battle.current_message = "💀 GOLPE FINAL! 💀".to_string();
-                                battle.waiting_for_cooldown = true;
-                            } else {
-                                let player_x = screen_width() / 2.0;
-                                let player_y = screen_height() * 0.8;
-                                battle.damage_particles.push(DamageParticle::new(
-                                    player_x,
-                                    player_y,
-                                    damage_dealt,
-                                ));
-                                battle.turn_cooldown = ENEMY_TURN_COOLDOWN;
-                                battle.waiting_for_cooldown = true;
-                            }
-                        } else {
-                            battle.current_message =
-                                "🃏 Enemy deck empty! Turn skipped.".to_string();
-                            battle.turn_cooldown = ENEMY_TURN_COOLDOWN;
-                            battle.waiting_for_cooldown = true;
-                        }
-                    } else {
 
-                        battle.turn_cooldown -= get_frame_time();
-                        battle.enemy_shake_timer =
-                            (battle.enemy_shake_timer - get_frame_time()).max(0.0);
-                        battle.card_animation_timer =
-                            (battle.card_animation_timer - get_frame_time()).max(0.0);
-                        for particle in &mut battle.damage_particles {
-                            particle.update(get_frame_time());
+                    let mut card_index = None;
+                    for i in 0..battle.engine.player.hand.cards.len().min(5) {
+                        let key = match i {
+                            0 => KeyCode::Key1,
+                            1 => KeyCode::Key2,
+                            2 => KeyCode::Key3,
+                            3 => KeyCode::Key4,
+                            4 => KeyCode::Key5,
+                            _ => continue,
+                        };
+                        if is_key_pressed(key) {
+                            card_index = Some(i);
+                            break;
                         }
-                        battle.damage_particles.retain(|p| p.is_alive());
-                        if battle.turn_cooldown <= 0.0 {
-                            battle.waiting_for_cooldown = false;
-                            battle.turn.next_turn();
-                            
-                            battle.add_turn_log("Player");
+                    }
+                    if is_mouse_button_pressed(MouseButton::Left) && clicked_card_index.is_some() {
+                        card_index = clicked_card_index;
+                    }
+                    if let Some(index) = card_index {
+                        if !battle.engine.can_play_card(index) {
+                            battle.current_message = "⚡ Energia insuficiente para essa carta!".to_string();
+                            return;
+                        }
+                        let events = battle.engine.apply(BattleAction::PlayCard(index));
+                        if !events.is_empty() {
+                            battle.selected_card_index = Some(index);
+                            Self::present_battle_events(&self.card_textures, battle, &events);
                         }
                     }
+                } else {
+                    let events = battle.engine.apply(BattleAction::EnemyAct);
+                    Self::present_battle_events(&self.card_textures, battle, &events);
                 }
             }
 
@@ -734,6 +747,230 @@ impl GameState {
                         self.winner = None;
                     }
                 }
+                if is_key_pressed(KeyCode::R) {
+                    let replay = BattleReplay::load(&platform_backend(), &save::replay_key(self.slot))
+                        .ok()
+                        .flatten()
+                        .or_else(|| self.last_replay.clone());
+                    if let Some(replay) = replay {
+                        self.app_state = AppState::Replay(Box::new(ReplayState::new(replay)));
+                    }
+                }
+            }
+            AppState::Replay(replay) => {
+                if is_key_pressed(KeyCode::Escape) {
+                    self.app_state = AppState::GameOver;
+                    return;
+                }
+                if is_key_pressed(KeyCode::Space) {
+                    replay.paused = !replay.paused;
+                }
+                if is_key_pressed(KeyCode::F) {
+                    replay.cycle_speed();
+                }
+
+                let frame_time = get_frame_time() * replay.speed();
+                replay.battle.update_effects(frame_time);
+                replay.battle.update_phase_banner(frame_time);
+                if replay.battle.is_final_blow {
+                    replay.battle.slow_motion_timer -= frame_time;
+                    if replay.battle.slow_motion_timer <= 0.0 {
+                        replay.battle.slow_motion_timer = 0.0;
+                        replay.battle.is_final_blow = false;
+                    }
+                }
+
+                let step_now = if replay.paused {
+                    is_key_pressed(KeyCode::Right)
+                } else {
+                    replay.step_timer -= frame_time;
+                    replay.step_timer <= 0.0
+                };
+                if step_now && !replay.is_finished() {
+                    let events = replay.step();
+                    Self::present_battle_events(&self.card_textures, &mut replay.battle, &events);
+                    replay.step_timer = REPLAY_STEP_DELAY;
+                }
+                replay.battle.turn_cooldown = replay.step_timer.max(0.0);
+            }
+            AppState::DeckEditor(editor) => {
+                if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+                    editor.select_previous();
+                }
+                if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+                    editor.select_next();
+                }
+                if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A) || is_key_pressed(KeyCode::Minus) {
+                    editor.remove_selected();
+                }
+                if is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D) || is_key_pressed(KeyCode::Equal) {
+                    editor.add_selected(&self.card_catalog);
+                }
+
+                if is_mouse_button_pressed(MouseButton::Left) {
+                    let (mouse_x, mouse_y) = mouse_position();
+                    if let Some((row, button)) = crate::state::ui::deck_editor::get_clicked_deck_editor_button(mouse_x, mouse_y, editor.card_ids.len()) {
+                        editor.selected_index = row;
+                        match button {
+                            DeckEditorButton::Remove => editor.remove_selected(),
+                            DeckEditorButton::Add => editor.add_selected(&self.card_catalog),
+                        }
+                    }
+                }
+
+                if is_key_pressed(KeyCode::Enter) {
+                    match editor.collection.validate_deck(&self.card_catalog) {
+                        Ok(()) => {
+                            self.collection = editor.collection.clone();
+                            self.save_progress();
+                            self.app_state = AppState::EnemySelection;
+                        }
+                        Err(e) => editor.message = Some(e.to_string()),
+                    }
+                } else if is_key_pressed(KeyCode::Escape) {
+                    self.app_state = AppState::EnemySelection;
+                }
+            }
+            AppState::CardReward(reward) => {
+                if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A) {
+                    reward.select_previous();
+                }
+                if is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D) {
+                    reward.select_next();
+                }
+                for (i, key_code) in [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3].into_iter().enumerate() {
+                    if is_key_pressed(key_code) && i < reward.choices.len() {
+                        reward.selected_index = i;
+                    }
+                }
+
+                let mut take_card = is_key_pressed(KeyCode::Enter);
+                if is_mouse_button_pressed(MouseButton::Left) {
+                    let (mouse_x, mouse_y) = mouse_position();
+                    if let Some(index) = crate::state::ui::card_reward::get_clicked_reward_index(mouse_x, mouse_y, reward.choices.len()) {
+                        reward.selected_index = index;
+                        take_card = true;
+                    }
+                }
+
+                let in_run = reward.in_run;
+                let next_state = || if in_run { AppState::RunMap(RunMapState::new(None)) } else { AppState::GameOver };
+                if take_card {
+                    if let Some(card) = reward.selected_card() {
+                        match self.run.as_mut() {
+                            Some(run) if in_run => run.deck.add_card_to_deck(&card.id),
+                            _ => self.collection.add_card(&card.id),
+                        }
+                        self.save_progress();
+                    }
+                    self.app_state = next_state();
+                } else if is_key_pressed(KeyCode::Escape) {
+                    self.app_state = next_state();
+                }
+            }
+            AppState::RunMap(map_state) => {
+                let Some(run) = &self.run else {
+                    self.app_state = AppState::Menu;
+                    return;
+                };
+                let available = run.available();
+                if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A) {
+                    map_state.select_previous();
+                }
+                if is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D) {
+                    map_state.select_next(available.len());
+                }
+
+                let mut chosen_node = None;
+                if is_key_pressed(KeyCode::Enter) {
+                    chosen_node = available.get(map_state.selected_index).copied();
+                }
+                if is_mouse_button_pressed(MouseButton::Left) {
+                    let (mouse_x, mouse_y) = mouse_position();
+                    if let Some(node) = crate::state::ui::run_map::get_clicked_run_node(run, mouse_x, mouse_y) {
+                        chosen_node = Some(node);
+                    }
+                }
+
+                if is_key_pressed(KeyCode::X) && (is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift)) {
+                    self.run = None;
+                    self.save_progress();
+                    self.app_state = AppState::Menu;
+                } else if is_key_pressed(KeyCode::Escape) {
+                    self.app_state = AppState::Menu;
+                } else if let Some(node) = chosen_node {
+                    self.enter_run_node(node);
+                }
+            }
+            AppState::ResumeBattle => {
+                if is_key_pressed(KeyCode::Enter) {
+                    self.resume_battle();
+                } else if is_key_pressed(KeyCode::Escape) {
+                    self.abandon_battle();
+                }
+            }
+            AppState::Shop(shop) => {
+                let in_run = shop.in_run;
+                if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+                    shop.select_previous();
+                }
+                if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+                    shop.select_next();
+                }
+
+                let mut action = None;
+                for (i, key_code) in [
+                    KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4, KeyCode::Key5, KeyCode::Key6,
+                ].into_iter().enumerate() {
+                    if is_key_pressed(key_code) {
+                        action = Some(ShopClick::Offer(i));
+                    }
+                }
+                if is_key_pressed(KeyCode::R) {
+                    action = Some(ShopClick::Remove(shop.selected_index));
+                }
+                if is_key_pressed(KeyCode::U) {
+                    action = Some(ShopClick::Upgrade(shop.selected_index));
+                }
+                if is_mouse_button_pressed(MouseButton::Left) {
+                    let (mouse_x, mouse_y) = mouse_position();
+                    if let Some(click) = crate::state::ui::shop::get_clicked_shop_element(shop, mouse_x, mouse_y) {
+                        action = Some(click);
+                    }
+                }
+
+                // Na run a loja mexe no baralho da run; fora dela, na coleção
+                let collection = match self.run.as_mut() {
+                    Some(run) if in_run => &mut run.deck,
+                    _ => &mut self.collection,
+                };
+                let changed = match action {
+                    Some(ShopClick::Offer(index)) => shop.buy(index, &mut self.gold, collection, &self.card_catalog),
+                    Some(ShopClick::Remove(index)) => {
+                        shop.selected_index = index;
+                        shop.remove_selected(&mut self.gold, collection, &self.card_catalog)
+                    }
+                    Some(ShopClick::Upgrade(index)) => {
+                        shop.selected_index = index;
+                        shop.upgrade_selected(&mut self.gold, collection, &self.card_catalog)
+                    }
+                    Some(ShopClick::Card(index)) => {
+                        shop.selected_index = index;
+                        false
+                    }
+                    None => false,
+                };
+                if changed {
+                    self.save_progress();
+                }
+
+                if is_key_pressed(KeyCode::Escape) {
+                    self.app_state = if in_run {
+                        AppState::RunMap(RunMapState::new(None))
+                    } else {
+                        AppState::EnemySelection
+                    };
+                }
             }
         }
     }
@@ -746,14 +983,44 @@ impl GameState {
         }
         
         match &self.app_state {
+            AppState::ProfileSelect(profiles) => {
+                crate::state::ui::profile_select::draw_profile_select(profiles, self.emoji_font.as_ref());
+            }
+            AppState::ResumeBattle => {
+                if let Some(suspended) = &self.suspended_battle {
+                    crate::state::ui::resume_battle::draw_resume_battle(suspended, self.emoji_font.as_ref());
+                }
+            }
             AppState::Menu => {
-                crate::state::ui::menu::draw_menu(&self.selection, &self.player_name, self.is_editing_name, self.emoji_font.as_ref());
+                crate::state::ui::menu::draw_menu(&self.selection, &self.player_name, self.is_editing_name, self.save_error.as_ref(), self.emoji_font.as_ref());
             }
             AppState::EnemySelection => {
-                crate::state::ui::enemy_selection::draw_enemy_selection(&self.enemies, self.selected_enemy_index, self.emoji_font.as_ref(), self.persistent_player.as_ref(), self.show_instructions);
+                crate::state::ui::enemy_selection::draw_enemy_selection(&self.enemies, self.selected_enemy_index, self.emoji_font.as_ref(), self.persistent_player.as_ref(), self.show_instructions, &self.seed_input, self.is_editing_seed);
+                crate::state::ui::enemy_selection::draw_gold_counter(self.gold, self.emoji_font.as_ref());
             }
             AppState::Battle(battle) => {
-                crate::state::ui::battle::draw_battle(battle, &self.card_textures, self.emoji_font.as_ref(), &self.enemies[self.selected_enemy_index].image.as_ref().unwrap());
+                crate::state::ui::battle::draw_battle(battle, &self.card_textures, self.emoji_font.as_ref());
+            }
+            AppState::Replay(replay) => {
+                crate::state::ui::replay::draw_replay(replay, &self.card_textures, self.emoji_font.as_ref());
+            }
+            AppState::DeckEditor(editor) => {
+                crate::state::ui::deck_editor::draw_deck_editor(editor, &self.card_catalog, &self.card_textures, self.emoji_font.as_ref());
+            }
+            AppState::CardReward(reward) => {
+                crate::state::ui::card_reward::draw_card_reward(reward, &self.card_textures, self.emoji_font.as_ref());
+            }
+            AppState::RunMap(map_state) => {
+                if let Some(run) = &self.run {
+                    crate::state::ui::run_map::draw_run_map(run, map_state, self.gold, self.emoji_font.as_ref());
+                }
+            }
+            AppState::Shop(shop) => {
+                let collection = match &self.run {
+                    Some(run) if shop.in_run => &run.deck,
+                    _ => &self.collection,
+                };
+                crate::state::ui::shop::draw_shop(shop, collection, &self.card_catalog, &self.card_textures, self.gold, self.emoji_font.as_ref());
             }
 
             AppState::SoundSettings => {
@@ -766,7 +1033,7 @@ impl GameState {
                 );
             }
             AppState::GameOver => {
-                crate::state::ui::game_over::draw_game_over(&self.winner);
+                crate::state::ui::game_over::draw_game_over(&self.winner, self.last_battle_seed);
             }
         }
     }
@@ -822,13 +1089,26 @@ impl GameState {
     }
 
     fn execute_menu_selection(&mut self) {
+        if !self.restore_pending {
+            self.save_error = None;
+        }
         match self.selection {
+            MenuSelection::Continue => {
+                self.continue_from_checkpoint();
+            }
             MenuSelection::Start => {
                 self.app_state = AppState::EnemySelection;
             }
+            MenuSelection::Run => {
+                self.open_run();
+            }
             MenuSelection::SoundSettings => {
                 self.app_state = AppState::SoundSettings;
             }
+            MenuSelection::Profiles => {
+                self.save_progress();
+                self.app_state = AppState::ProfileSelect(ProfileState::new(None));
+            }
             MenuSelection::Quit => {
                 std::process::exit(0);
             }
@@ -859,49 +1139,279 @@ impl GameState {
             return;
         }
 
-        let mut deck = Deck::new();
+        let deck = match self.collection.validate_deck(&self.card_catalog) {
+            Ok(()) => self.collection.build_deck(&self.card_catalog),
+            Err(e) => {
+                eprintln!("⚠️ {}, using the starter deck", e);
+                Deck::new(&self.card_catalog)
+            }
+        };
         
         let player = if let Some(ref persistent_player) = self.persistent_player {
             persistent_player.clone()
         } else {
-            Player::new(&self.player_name, &mut deck)
+            Player::new(&self.player_name)
         };
 
-        // testando se é melhor resetar a mão do jogador
-        // player.hand = Hand::new_from_deck(&mut deck, 5);
-
-        let enemy = Enemy::new(
+        let mut enemy = Enemy::new(
             &selected_enemy.name,
             selected_enemy.health,
             selected_enemy.max_health,
             selected_enemy.attack,  
             selected_enemy.defense, 
-            &mut deck,
             selected_enemy.image.as_ref().unwrap(),
+            selected_enemy.card_chances.card_pool(&self.card_catalog),
         );
+        enemy.attack_sound = selected_enemy.attack_sound.clone();
+        enemy.ai = selected_enemy.ai.clone();
+        enemy.phases = selected_enemy
+            .phases
+            .iter()
+            .map(|phase| phase.to_phase(&self.card_catalog, &self.enemy_roster))
+            .collect();
 
-        let mut battle_state = BattleState {
-            player,
-            enemy,
-            deck,
-            turn: GameTurn::new(MAX_TURNS),
-            current_message: "The battle has begun!".to_string(),
-            music_started: false,
-            turn_cooldown: 0.0,
-            waiting_for_cooldown: false,
-            selected_card_index: None,
-            card_animation_timer: 0.0,
-            enemy_shake_timer: 0.0,
-            damage_particles: Vec::new(),
-            battle_log: Vec::new(),
-            log_scroll_offset: 0.0,
-            slow_motion_timer: 0.0,
-            is_final_blow: false,
-        };
+        // A escolta entra com os atributos do primeiro encontro
+        let mut enemies = vec![enemy];
+        enemies.extend(
+            selected_enemy
+                .escort
+                .iter()
+                .filter_map(|id| self.enemy_roster.get(*id))
+                .map(|definition| definition.to_enemy(&self.card_catalog))
+                .take(MAX_ENCOUNTER_ENEMIES - 1),
+        );
+
+        let seed = self.seed_input.trim().parse::<u64>().unwrap_or_else(|_| BattleEngine::random_seed());
+        let mut battle_state = BattleState::new(BattleEngine::new(player, enemies, deck, self.status_registry.clone(), MAX_TURNS, seed));
         
-        battle_state.add_battle_start_log(&selected_enemy.name);
+        battle_state.add_battle_start_log();
         
-        self.app_state = AppState::Battle(battle_state);
+        self.save_checkpoint();
+        self.app_state = AppState::Battle(Box::new(battle_state));
+    }
+
+    /// Reage aos eventos do motor com sons, animações e o tempo de espera do turno
+    fn present_battle_events(card_textures: &CardTextureManager, battle: &mut BattleState, events: &[BattleEvent]) {
+        battle.record_events(events);
+
+        for event in events {
+            match event {
+                BattleEvent::CardPlayed { actor: Combatant::Player, .. } => {
+                    card_textures.play_card_use_sound();
+                    battle.card_animation_timer = 0.3;
+                }
+                BattleEvent::CardPlayed { actor: Combatant::Enemy(index), .. } => {
+                    card_textures.play_enemy_sound(battle.engine.enemies[*index].attack_sound.as_deref());
+                }
+                BattleEvent::Damage { target, damage, .. } if !battle.engine.is_over() => {
+                    let (x, y) = match target {
+                        Combatant::Enemy(index) => {
+                            battle.enemy_shake_timer = ENEMY_SHAKE_DURATION;
+                            battle.shaken_enemy = *index;
+                            let rect = enemy_sprite_rect(*index, battle.engine.enemies.len());
+                            (rect.center().x, rect.y + rect.h / 3.0)
+                        }
+                        Combatant::Player => (screen_width() / 2.0, screen_height() * 0.8),
+                    };
+                    let particle = DamageParticle::new(x, y, *damage, &mut battle.effects_rng);
+                    battle.damage_particles.push(particle);
+                }
+                _ => {}
+            }
+        }
+
+        if battle.engine.is_over() {
+            battle.is_final_blow = true;
+            battle.slow_motion_timer = 1.0;
+        } else {
+            battle.turn_cooldown = match battle.engine.current_actor() {
+                Combatant::Player if battle.engine.player_can_act() => CARD_PLAY_COOLDOWN,
+                Combatant::Player => PLAYER_TURN_COOLDOWN,
+                Combatant::Enemy(_) => ENEMY_TURN_COOLDOWN,
+            };
+            // A luta espera o aviso de troca de fase do chefe
+            if events.iter().any(|event| matches!(event, BattleEvent::PhaseChanged { .. })) {
+                battle.turn_cooldown = battle.turn_cooldown.max(PHASE_TRANSITION_DURATION);
+            }
+        }
+        battle.waiting_for_cooldown = true;
+    }
+
+    fn finish_battle(&mut self, outcome: Option<BattleOutcome>) {
+        let (winner, reason) = match outcome {
+            Some(BattleOutcome::PlayerWon) => ("Jogador", "Player"),
+            Some(BattleOutcome::EnemyWon) => ("Inimigo", "Enemy"),
+            Some(BattleOutcome::Draw) | None => ("Empate", "Playable turns have ended!"),
+        };
+
+        if let AppState::Battle(battle) = &mut self.app_state {
+            if outcome != Some(BattleOutcome::Draw) {
+                battle.add_battle_end_log(winner);
+            }
+            self.last_battle_seed = Some(battle.engine.seed());
+
+            let replay = battle.engine.replay().clone();
+            if let Err(e) = replay.save(&platform_backend(), &save::replay_key(self.slot)) {
+                eprintln!("⚠️ Could not record replay: {}", e);
+            }
+            self.last_replay = Some(replay);
+        }
+
+        if self.run.as_ref().is_some_and(|run| run.active_node.is_some()) {
+            self.finish_run_battle(outcome);
+            return;
+        }
+
+        let reward = (outcome == Some(BattleOutcome::PlayerWon)).then(|| self.card_reward_for_selected_enemy());
+
+        self.winner = Some(winner.to_string());
+        self.on_battle_end(reason);
+        self.app_state = match reward {
+            Some(reward) if !reward.choices.is_empty() => AppState::CardReward(reward),
+            _ => AppState::GameOver,
+        };
+    }
+
+    /// Sorteia as cartas de recompensa pelo nível e vitórias do inimigo, antes de ele ser escalado.
+    /// O sorteio usa a semente da batalha, então a mesma semente oferece as mesmas cartas.
+    fn card_reward_for_selected_enemy(&self) -> CardRewardState {
+        let enemy = &self.enemies[self.selected_enemy_index];
+        let mut rng = StdRng::seed_from_u64(self.last_battle_seed.unwrap_or_default());
+        let choices = roll_card_rewards(&self.card_catalog, enemy.level, enemy.times_defeated, &mut rng);
+        CardRewardState::new(choices, &enemy.name)
+    }
+
+    /// Abre a run salva ou começa uma nova com o baralho atual e a semente digitada, se houver
+    fn open_run(&mut self) {
+        match self.run.as_mut() {
+            // Uma batalha interrompida sem ser suspensa não volta; a parada fica disponível de novo
+            Some(run) => run.active_node = None,
+            None => {
+                let seed = self.seed_input.trim().parse::<u64>().unwrap_or_else(|_| BattleEngine::random_seed());
+                let deck = match self.collection.validate_deck(&self.card_catalog) {
+                    Ok(()) => self.collection.build_deck(&self.card_catalog),
+                    Err(_) => Deck::new(&self.card_catalog),
+                };
+                let max_health = self
+                    .persistent_player
+                    .as_ref()
+                    .map_or_else(|| Player::new(&self.player_name).max_health, |player| player.max_health);
+                let run_deck = CardCollection::from_cards(&deck.cards);
+                self.run = Some(RunState::new(seed, &self.enemy_roster, max_health, run_deck));
+                self.save_progress();
+            }
+        }
+        self.app_state = AppState::RunMap(RunMapState::new(None));
+    }
+
+    /// Abre a loja fora da run com cartas novas a cada visita
+    fn open_shop(&mut self) {
+        let level = self.persistent_player.as_ref().map_or(1, |player| player.level);
+        let mut rng = StdRng::seed_from_u64(BattleEngine::random_seed());
+        let offers = roll_cards(&self.card_catalog, level, 0, SHOP_OFFER_COUNT, &mut rng);
+        self.app_state = AppState::Shop(ShopState::new(offers, &self.collection, &self.card_catalog, false));
+    }
+
+    /// Resolve a parada escolhida no mapa: batalhas abrem a luta e as outras paradas acontecem na hora
+    fn enter_run_node(&mut self, node: usize) {
+        let Some(run) = self.run.as_mut() else {
+            return;
+        };
+        let message = match run.map.nodes[node].kind {
+            NodeKind::Battle | NodeKind::Elite | NodeKind::Boss => {
+                self.start_run_battle(node);
+                return;
+            }
+            NodeKind::Rest => {
+                let healed = run.heal(RUN_REST_HEAL_PERCENT);
+                run.visit(node);
+                format!("🔥 You rest by the campfire and recover {} HP.", healed)
+            }
+            NodeKind::Event => {
+                let message = run.resolve_event(node, &self.card_catalog);
+                run.visit(node);
+                message
+            }
+            NodeKind::Shop => {
+                let offers = run.shop_offers(node, &self.card_catalog);
+                run.visit(node);
+                let shop = ShopState::new(offers, &run.deck, &self.card_catalog, true);
+                self.save_progress();
+                self.app_state = AppState::Shop(shop);
+                return;
+            }
+        };
+        self.save_progress();
+        self.app_state = AppState::RunMap(RunMapState::new(Some(message)));
+    }
+
+    /// Batalha da run: a vida vem da run e os inimigos ficam mais fortes a cada andar
+    fn start_run_battle(&mut self, node: usize) {
+        let Some(run) = self.run.as_mut() else {
+            return;
+        };
+        let enemies = run.encounter(node, &self.enemy_roster, &self.card_catalog);
+        if enemies.is_empty() {
+            run.visit(node);
+            self.save_progress();
+            return;
+        }
+
+        let mut player = self.persistent_player.clone().unwrap_or_else(|| Player::new(&self.player_name));
+        player.health = run.health;
+        player.max_health = run.max_health;
+        run.active_node = Some(node);
+
+        let deck = run.build_deck(&self.card_catalog);
+        let engine = BattleEngine::new(player, enemies, deck, self.status_registry.clone(), MAX_TURNS, run.node_seed(node));
+        let mut battle_state = BattleState::new(engine);
+        battle_state.add_battle_start_log();
+
+        self.save_checkpoint();
+        self.app_state = AppState::Battle(Box::new(battle_state));
+    }
+
+    /// Fim de uma batalha da run: a vida segue para o mapa, a derrota encerra a run e vencer o chefe a completa
+    fn finish_run_battle(&mut self, outcome: Option<BattleOutcome>) {
+        let health = match &self.app_state {
+            AppState::Battle(battle) => battle.engine.player.health,
+            _ => 0,
+        };
+        let Some(run) = self.run.as_mut() else {
+            return;
+        };
+        let Some(node) = run.active_node else {
+            return;
+        };
+
+        let next_state = match outcome {
+            Some(BattleOutcome::PlayerWon) => {
+                run.health = health.max(1);
+                self.gold += gold_reward(run.map.nodes[node].row as u32 + 1);
+                run.visit(node);
+                if run.is_complete() {
+                    self.run = None;
+                    self.winner = Some("Jogador".to_string());
+                    AppState::GameOver
+                } else {
+                    let choices = run.card_choices(node, &self.card_catalog);
+                    AppState::CardReward(CardRewardState::for_run(choices, "Victory! Pick one card to add to your run deck."))
+                }
+            }
+            Some(BattleOutcome::EnemyWon) => {
+                self.run = None;
+                self.winner = Some("Inimigo".to_string());
+                AppState::GameOver
+            }
+            // O limite de turnos não conclui a parada; a vida perdida fica
+            Some(BattleOutcome::Draw) | None => {
+                run.health = health.max(1);
+                run.active_node = None;
+                AppState::RunMap(RunMapState::new(Some("⏳ The fight dragged on and you retreated.".to_string())))
+            }
+        };
+        self.save_checkpoint();
+        self.app_state = next_state;
     }
 
     fn get_clicked_enemy_index(&self, mouse_x: f32, mouse_y: f32) -> Option<usize> {
@@ -911,19 +1421,22 @@ impl GameState {
     pub fn on_battle_end(&mut self, winner: &str) {
         if winner == "Player" {
             if let AppState::Battle(battle) = &mut self.app_state {
-                let player = &mut battle.player;
+                let player = &mut battle.engine.player;
             
                 let enemy = &mut self.enemies[self.selected_enemy_index];
                 enemy.is_defeated = true;
                 enemy.times_defeated += 1;
             
                 let exp_gained = enemy.level * EXP_MULTIPLIER_PER_LEVEL + BASE_EXP_GAIN;
+                self.gold += gold_reward(enemy.level);
                 let leveled_up = player.gain_experience(exp_gained);
                 
                 if leveled_up {
                 } else {
                 }
             
+                player.end_battle();
+
                 let max_health_increase: u32 = (player.max_health as f32 * HEALTH_INCREASE_PERCENT) as u32;
                 player.max_health = player.max_health.saturating_add(max_health_increase);
             
@@ -943,7 +1456,7 @@ impl GameState {
         }
         
         
-        self.save_progress();
+        self.save_checkpoint();
     }
 
     fn scale_enemy(&mut self, enemy_index: usize) {
@@ -967,19 +1480,27 @@ impl GameState {
         
     }
 
-    const SAVE_FILE: &'static str = "save_game.json";
+    pub fn save_progress(&mut self) {
+        self.write_save(false);
+    }
 
-    pub fn save_progress(&self) {
-        let player_save_data = self.persistent_player.as_ref().map(|player| PlayerSaveData {
-            name: player.name.clone(),
-            health: player.max_health,
-            max_health: player.max_health,
-            attack: player.attack,
-            defense: player.defense,
-            level: player.level,
-            experience: player.experience,
-            experience_to_next_level: player.experience_to_next_level,
-        });
+    /// Salvamento automático no começo e no fim das batalhas; o save anterior vira backup
+    pub fn save_checkpoint(&mut self) {
+        self.write_save(true);
+    }
+
+    fn write_save(&mut self, checkpoint: bool) {
+        if self.restore_pending {
+            return;
+        }
+        if let Err(e) = save::store(&platform_backend(), &save::slot_key(self.slot), &self.save_data(), checkpoint) {
+            eprintln!("⚠️ {}", e);
+            self.save_error = Some(e);
+        }
+    }
+
+    fn save_data(&self) -> SaveData {
+        let player_save_data = self.persistent_player.as_ref().map(PlayerSaveData::from_player);
 
         let sound_settings = SoundSettings {
             music_volume: self.music_volume,
@@ -988,69 +1509,219 @@ impl GameState {
             sfx_enabled: self.sfx_enabled,
         };
 
-        let save_data = SaveData {
+        SaveData {
+            version: SAVE_VERSION,
             enemies: self.enemies.clone(),
             persistent_player: player_save_data,
             player_name: self.player_name.clone(),
             sound_settings,
-        };
+            collection: self.collection.clone(),
+            run: self.run.clone(),
+            gold: self.gold,
+            battle: None,
+        }
+    }
 
-        if let Ok(json) = serde_json::to_string_pretty(&save_data) {
-            if let Err(_e) = fs::write(Self::SAVE_FILE, json) {
-            } else {
+    /// Fechar a janela no meio de uma luta guarda a batalha no save para ser retomada na próxima vez
+    fn quit(&mut self) {
+        if matches!(self.app_state, AppState::ProfileSelect(_)) {
+            std::process::exit(0);
+        }
+        // Uma luta que já terminou é concluída em vez de suspensa
+        if let AppState::Battle(battle) = &self.app_state {
+            if battle.engine.is_over() {
+                let outcome = battle.engine.outcome();
+                self.finish_battle(outcome);
             }
         }
+
+        let mut save_data = self.save_data();
+        save_data.battle = match std::mem::replace(&mut self.app_state, AppState::Menu) {
+            AppState::Battle(battle) => Some(SuspendedBattle { battle: *battle, enemy_index: self.selected_enemy_index }),
+            _ => self.suspended_battle.take(),
+        };
+        if !self.restore_pending
+            && let Err(e) = save::store(&platform_backend(), &save::slot_key(self.slot), &save_data, false)
+        {
+            eprintln!("⚠️ {}", e);
+        }
+        std::process::exit(0);
+    }
+
+    /// Volta para a batalha suspensa exatamente no turno em que ela parou
+    fn resume_battle(&mut self) {
+        let Some(SuspendedBattle { mut battle, enemy_index }) = self.suspended_battle.take() else {
+            self.app_state = AppState::Menu;
+            return;
+        };
+        self.selected_enemy_index = enemy_index.min(self.enemies.len().saturating_sub(1));
+        self.last_battle_seed = Some(battle.engine.seed());
+        battle.add_log("Battle resumed".to_string(), LIME);
+        self.save_progress();
+        self.app_state = AppState::Battle(Box::new(battle));
+    }
+
+    /// Descarta a batalha suspensa; a parada da run volta a ficar disponível
+    fn abandon_battle(&mut self) {
+        self.suspended_battle = None;
+        if let Some(run) = self.run.as_mut() {
+            run.active_node = None;
+        }
+        self.save_progress();
+        self.app_state = AppState::Menu;
+    }
+
+    /// Entra no perfil do slot, começando do zero e carregando o save dele
+    fn open_profile(&mut self, slot: usize) {
+        self.reset_profile();
+        self.slot = slot;
+        self.load_progress();
+        if self.music_started {
+            self.play_music_with_current_settings();
+        }
+        self.selection = MenuSelection::Start;
+        self.app_state = if self.suspended_battle.is_some() { AppState::ResumeBattle } else { AppState::Menu };
+    }
+
+    /// Cria um perfil novo no slot vazio com o nome escolhido
+    fn create_profile(&mut self, slot: usize, name: String) {
+        self.reset_profile();
+        self.slot = slot;
+        self.player_name = name;
+        self.save_progress();
+        self.selection = MenuSelection::Start;
+        self.app_state = AppState::Menu;
+    }
+
+    /// Limpa o progresso do perfil anterior antes de trocar de slot
+    fn reset_profile(&mut self) {
+        self.reset_progress();
+        self.player_name = "Player".to_string();
+        self.selected_enemy_index = 0;
+        self.last_battle_seed = None;
+        self.last_replay = None;
+        self.save_error = None;
+        self.restore_pending = false;
+        self.suspended_battle = None;
     }
 
+    const RESTORE_HINT: &'static str = "choose Continue to restore the last checkpoint (nothing is saved until then)";
+
     pub fn load_progress(&mut self) {
-        if Path::new(Self::SAVE_FILE).exists() {
-            if let Ok(json) = fs::read_to_string(Self::SAVE_FILE) {
-                if let Ok(save_data) = serde_json::from_str::<SaveData>(&json) {
-                    self.enemies = save_data.enemies;
-                            let default_enemies = get_default_enemies();
-        for (index, enemy) in self.enemies.iter_mut().enumerate() {
-            if index < default_enemies.len() && enemy.image.as_ref().unwrap() != default_enemies[index].image.as_ref().unwrap() {
-                enemy.image = default_enemies[index].image.clone();
-                enemy.emoji = default_enemies[index].emoji.clone();
-                enemy.name = default_enemies[index].name.clone();
-                enemy.base_health = default_enemies[index].base_health;
-                enemy.base_attack = default_enemies[index].base_attack;
-                enemy.base_defense = default_enemies[index].base_defense;
-                enemy.health = default_enemies[index].health;
-                enemy.max_health = default_enemies[index].max_health;
+        let backend = platform_backend();
+        let key = save::slot_key(self.slot);
+        match save::load(&backend, &key) {
+            Ok(Some(save_data)) => self.apply_save(save_data),
+            Ok(None) => {
+                if save::latest_backup(&backend, &key).is_some() {
+                    self.restore_pending = true;
+                    self.save_error = Some(GameError::SaveLoadError(format!(
+                        "the save is missing; {}",
+                        Self::RESTORE_HINT
+                    )));
+                }
             }
-        }
-                    self.player_name = save_data.player_name;
-                    
-                    if let Some(player_data) = save_data.persistent_player {
-                        let mut deck = Deck::new();
-                        let mut player = Player::new(&player_data.name, &mut deck);
-                        player.health = player_data.max_health;
-                        player.max_health = player_data.max_health;
-                        player.attack = player_data.attack;
-                        player.defense = player_data.defense;
-                        player.level = player_data.level;
-                        player.experience = player_data.experience;
-                        player.experience_to_next_level = player_data.experience_to_next_level;
-                        self.persistent_player = Some(player);
-                    }
-                    
-                    self.music_volume = save_data.sound_settings.music_volume;
-                    self.sfx_volume = save_data.sound_settings.sfx_volume;
-                    self.music_enabled = save_data.sound_settings.music_enabled;
-                    self.sfx_enabled = save_data.sound_settings.sfx_enabled;
-                    
+            Err(error) => {
+                eprintln!("⚠️ {}", error);
+                let message = match error {
+                    GameError::SaveLoadError(message) => message,
+                    other => other.to_string(),
+                };
+                let restorable = save::latest_backup(&backend, &key).is_some();
+                // Um save que não pôde ser posto de lado também não pode ser coberto por um perfil vazio
+                self.restore_pending = restorable || backend.exists(&key);
+                let message = if restorable {
+                    format!("{}; {}", message, Self::RESTORE_HINT)
+                } else if self.restore_pending {
+                    format!("{}; nothing is saved to this slot", message)
                 } else {
+                    message
+                };
+                self.save_error = Some(GameError::SaveLoadError(message));
+            }
+        }
+    }
+
+    /// Volta ao último checkpoint legível do slot e retoma a run, se houver uma
+    fn continue_from_checkpoint(&mut self) {
+        match save::load_latest(&platform_backend(), &save::slot_key(self.slot)) {
+            Ok(Some(save_data)) => {
+                self.reset_profile();
+                self.apply_save(save_data);
+                if self.suspended_battle.is_some() {
+                    self.app_state = AppState::ResumeBattle;
+                    return;
                 }
+                self.app_state = match self.run.as_mut() {
+                    Some(run) => {
+                        run.active_node = None;
+                        AppState::RunMap(RunMapState::new(Some("Restored from the last checkpoint.".to_string())))
+                    }
+                    None => AppState::EnemySelection,
+                };
+                self.save_progress();
+            }
+            Ok(None) => {
+                self.save_error = Some(GameError::SaveLoadError("there is no checkpoint to continue from".to_string()));
             }
+            Err(error) => self.save_error = Some(error),
+        }
+    }
+
+    fn apply_save(&mut self, save_data: SaveData) {
+        self.enemies = save_data.enemies;
+        self.sync_enemies_with_roster();
+        self.player_name = save_data.player_name;
+
+        if let Some(player_data) = save_data.persistent_player {
+            self.persistent_player = Some(player_data.to_player());
+        }
+
+        self.music_volume = save_data.sound_settings.music_volume;
+        self.sfx_volume = save_data.sound_settings.sfx_volume;
+        self.music_enabled = save_data.sound_settings.music_enabled;
+        self.sfx_enabled = save_data.sound_settings.sfx_enabled;
+
+        if !save_data.collection.is_empty() {
+            self.collection = save_data.collection;
+        }
+        self.run = save_data.run;
+        self.gold = save_data.gold;
+        self.suspended_battle = save_data.battle;
+    }
+
+    /// Alinha os inimigos salvos com o roster pelo id: atualiza os dados fixos,
+    /// preserva o progresso e acrescenta inimigos novos na ordem de desbloqueio
+    pub fn sync_enemies_with_roster(&mut self) {
+        let mut synced: Vec<EnemyInfo> = Vec::with_capacity(self.enemy_roster.enemies.len());
+
+        for definition in &self.enemy_roster.enemies {
+            let enemy = match self.enemies.iter().find(|enemy| enemy.id == definition.id) {
+                Some(saved) => {
+                    let mut enemy = saved.clone();
+                    enemy.refresh_from_definition(definition);
+                    enemy
+                }
+                None => {
+                    let mut enemy = EnemyInfo::from_definition(definition);
+                    enemy.is_unlocked = synced.last().is_none_or(|previous| previous.is_defeated);
+                    enemy
+                }
+            };
+            synced.push(enemy);
         }
+
+        self.enemies = synced;
+        self.selected_enemy_index = self.selected_enemy_index.min(self.enemies.len().saturating_sub(1));
     }
 
     pub fn reset_progress(&mut self) {
-        self.enemies = get_default_enemies();
+        self.enemies = default_enemies(&self.enemy_roster);
         
         self.persistent_player = None;
-        
+        self.collection = CardCollection::starter(&self.card_catalog);
+        self.run = None;
+        self.gold = 0;
     }
 
     pub fn play_music_with_current_settings(&mut self) {
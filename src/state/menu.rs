@@ -1,6 +1,33 @@
-#[derive(PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MenuSelection {
+    Continue,
     Start,
+    Run,
     SoundSettings,
+    Profiles,
     Quit,
-}
\ No newline at end of file
+}
+
+impl MenuSelection {
+    /// Opções na ordem em que aparecem no menu
+    pub const ALL: [MenuSelection; 6] = [
+        MenuSelection::Continue,
+        MenuSelection::Start,
+        MenuSelection::Run,
+        MenuSelection::SoundSettings,
+        MenuSelection::Profiles,
+        MenuSelection::Quit,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|option| *option == self).unwrap_or(0)
+    }
+
+    pub fn previous(self) -> Self {
+        Self::ALL[self.index().saturating_sub(1)]
+    }
+
+    pub fn next(self) -> Self {
+        Self::ALL[(self.index() + 1).min(Self::ALL.len() - 1)]
+    }
+}
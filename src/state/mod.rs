@@ -2,5 +2,11 @@ pub mod damage_particle;
 pub mod menu;
 pub mod battle_state;
 pub mod game_state;
+pub mod replay_state;
+pub mod deck_editor_state;
+pub mod card_reward_state;
+pub mod run_map_state;
+pub mod shop_state;
+pub mod profile_state;
 pub mod ui;
 
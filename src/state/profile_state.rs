@@ -0,0 +1,110 @@
+use crate::config::config::*;
+use crate::save::{self, SlotSummary};
+use crate::save_backend::platform_backend;
+
+/// O que cada slot tem, como o seletor de perfis mostra
+pub enum ProfileSlot {
+    Empty,
+    Profile(SlotSummary),
+    /// Save ilegível e sem backup legível. Não é vaga livre: abrir o perfil põe o arquivo de lado
+    /// antes de gravar qualquer coisa, e só o Delete o descarta
+    Corrupt,
+}
+
+/// Seletor de perfis: os slots de save, o nome do perfil sendo criado e a confirmação de exclusão
+pub struct ProfileState {
+    pub slots: Vec<ProfileSlot>,
+    pub selected_index: usize,
+    pub message: Option<String>,
+    /// Nome digitado para o perfil novo no slot selecionado
+    pub new_name: Option<String>,
+    pub confirm_delete: bool,
+}
+
+impl ProfileState {
+    pub fn new(message: Option<String>) -> Self {
+        let mut profiles = Self {
+            slots: Vec::new(),
+            selected_index: 0,
+            message,
+            new_name: None,
+            confirm_delete: false,
+        };
+        if let Err(error) = save::adopt_legacy_save(&platform_backend()) {
+            profiles.message = Some(error.to_string());
+        }
+        profiles.refresh();
+        profiles
+    }
+
+    /// Relê os slots sem alterá-los; um save ilegível mostra o backup ou fica marcado como corrompido,
+    /// e o erro aparece na tela
+    pub fn refresh(&mut self) {
+        let backend = platform_backend();
+        self.slots = (0..SAVE_SLOT_COUNT)
+            .map(|slot| match save::read_slot(&backend, slot) {
+                Ok(None) => ProfileSlot::Empty,
+                Ok(Some(summary)) => {
+                    if let Some(warning) = &summary.warning {
+                        self.message = Some(warning.clone());
+                    }
+                    ProfileSlot::Profile(summary)
+                }
+                Err(error) => {
+                    self.message = Some(format!("Slot {}: {}", slot + 1, error));
+                    ProfileSlot::Corrupt
+                }
+            })
+            .collect();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+        self.confirm_delete = false;
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected_index + 1 < self.slots.len() {
+            self.selected_index += 1;
+        }
+        self.confirm_delete = false;
+    }
+
+    pub fn is_selected_empty(&self) -> bool {
+        matches!(self.slots.get(self.selected_index), Some(ProfileSlot::Empty))
+    }
+
+    /// Copia o perfil selecionado para o primeiro slot vazio
+    pub fn copy_selected(&mut self) {
+        if !matches!(self.slots.get(self.selected_index), Some(ProfileSlot::Profile(_))) {
+            return;
+        }
+        let Some(target) = self.slots.iter().position(|slot| matches!(slot, ProfileSlot::Empty)) else {
+            self.message = Some("No empty slot to copy to".to_string());
+            return;
+        };
+        self.message = Some(match save::copy_slot(&platform_backend(), self.selected_index, target) {
+            Ok(()) => format!("Slot {} copied to slot {}", self.selected_index + 1, target + 1),
+            Err(error) => error.to_string(),
+        });
+        self.refresh();
+    }
+
+    /// Apaga o perfil selecionado; o primeiro pedido só pede confirmação
+    pub fn delete_selected(&mut self) {
+        if self.is_selected_empty() {
+            return;
+        }
+        if !self.confirm_delete {
+            self.confirm_delete = true;
+            self.message = Some(format!("Press Delete again to erase slot {}", self.selected_index + 1));
+            return;
+        }
+        self.confirm_delete = false;
+        self.message = Some(match save::delete_slot(&platform_backend(), self.selected_index) {
+            Ok(()) => format!("Slot {} deleted", self.selected_index + 1),
+            Err(error) => error.to_string(),
+        });
+        self.refresh();
+    }
+}
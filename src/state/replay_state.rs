@@ -0,0 +1,53 @@
+use crate::battle_engine::BattleEvent;
+use crate::replay::BattleReplay;
+use crate::state::battle_state::BattleState;
+
+/// Velocidades disponíveis no avanço rápido do replay
+pub const REPLAY_SPEEDS: [f32; 3] = [1.0, 2.0, 4.0];
+
+/// Reprodução passo a passo de uma batalha gravada
+pub struct ReplayState {
+    pub battle: BattleState,
+    pub replay: BattleReplay,
+    pub next_action: usize,
+    pub paused: bool,
+    pub speed_index: usize,
+    pub step_timer: f32,
+}
+
+impl ReplayState {
+    pub fn new(replay: BattleReplay) -> Self {
+        let mut battle = BattleState::new(replay.to_engine());
+        battle.add_battle_start_log();
+
+        Self {
+            battle,
+            replay,
+            next_action: 0,
+            paused: false,
+            speed_index: 0,
+            step_timer: 0.0,
+        }
+    }
+
+    pub fn speed(&self) -> f32 {
+        REPLAY_SPEEDS[self.speed_index]
+    }
+
+    pub fn cycle_speed(&mut self) {
+        self.speed_index = (self.speed_index + 1) % REPLAY_SPEEDS.len();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_action >= self.replay.actions.len()
+    }
+
+    /// Aplica a próxima ação gravada no motor e devolve os eventos gerados
+    pub fn step(&mut self) -> Vec<BattleEvent> {
+        let Some(&action) = self.replay.actions.get(self.next_action) else {
+            return Vec::new();
+        };
+        self.next_action += 1;
+        self.battle.engine.apply(action)
+    }
+}
@@ -0,0 +1,24 @@
+/// Tela do mapa da run: a parada escolhida entre as disponíveis e o resultado da última parada
+pub struct RunMapState {
+    pub selected_index: usize,
+    pub message: Option<String>,
+}
+
+impl RunMapState {
+    pub fn new(message: Option<String>) -> Self {
+        Self {
+            selected_index: 0,
+            message,
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self, available: usize) {
+        if self.selected_index + 1 < available {
+            self.selected_index += 1;
+        }
+    }
+}
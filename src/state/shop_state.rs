@@ -0,0 +1,132 @@
+use crate::card_catalog::CardCatalog;
+use crate::collection::CardCollection;
+use crate::config::config::*;
+use crate::deck::Card;
+
+/// Carta à venda e seu preço
+pub struct ShopOffer {
+    pub card: Card,
+    pub price: u32,
+    pub sold: bool,
+}
+
+/// Loja: compra de cartas, remoção e melhoria das cartas do baralho.
+/// As compras valem na hora, sobre a coleção ou sobre o baralho da run.
+pub struct ShopState {
+    pub offers: Vec<ShopOffer>,
+    /// Ids das cartas possuídas, na ordem do catálogo
+    pub card_ids: Vec<String>,
+    pub selected_index: usize,
+    pub message: Option<String>,
+    /// A loja é uma parada da run e mexe no baralho da run
+    pub in_run: bool,
+}
+
+impl ShopState {
+    pub fn new(cards: Vec<Card>, collection: &CardCollection, catalog: &CardCatalog, in_run: bool) -> Self {
+        let offers = cards
+            .into_iter()
+            .map(|card| ShopOffer {
+                price: card.rarity.shop_price(),
+                card,
+                sold: false,
+            })
+            .collect();
+        let mut shop = Self {
+            offers,
+            card_ids: Vec::new(),
+            selected_index: 0,
+            message: None,
+            in_run,
+        };
+        shop.refresh_cards(collection, catalog);
+        shop
+    }
+
+    fn refresh_cards(&mut self, collection: &CardCollection, catalog: &CardCatalog) {
+        self.card_ids = catalog
+            .cards
+            .iter()
+            .filter(|definition| collection.owned_copies(&definition.id) > 0)
+            .map(|definition| definition.id.clone())
+            .collect();
+        self.selected_index = self.selected_index.min(self.card_ids.len().saturating_sub(1));
+    }
+
+    pub fn selected_card_id(&self) -> Option<&str> {
+        self.card_ids.get(self.selected_index).map(String::as_str)
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected_index + 1 < self.card_ids.len() {
+            self.selected_index += 1;
+        }
+    }
+
+    /// Compra a oferta; na run a carta vai direto para o baralho. Devolve se algo mudou.
+    pub fn buy(&mut self, index: usize, gold: &mut u32, collection: &mut CardCollection, catalog: &CardCatalog) -> bool {
+        let Some(offer) = self.offers.get_mut(index).filter(|offer| !offer.sold) else {
+            return false;
+        };
+        if *gold < offer.price {
+            self.message = Some(format!("Not enough gold for {} ({} needed)", offer.card.name, offer.price));
+            return false;
+        }
+        *gold -= offer.price;
+        offer.sold = true;
+        if self.in_run {
+            collection.add_card_to_deck(&offer.card.id);
+        } else {
+            collection.add_card(&offer.card.id);
+        }
+        self.message = Some(format!("Bought {}", offer.card.name));
+        self.refresh_cards(collection, catalog);
+        true
+    }
+
+    /// Descarta uma cópia da carta selecionada, sem deixar o baralho abaixo do mínimo
+    pub fn remove_selected(&mut self, gold: &mut u32, collection: &mut CardCollection, catalog: &CardCatalog) -> bool {
+        let Some(id) = self.selected_card_id().map(str::to_string) else {
+            return false;
+        };
+        let shrinks_deck = collection.deck_copies(&id) >= collection.owned_copies(&id);
+        if shrinks_deck && collection.deck_size() <= MIN_DECK_SIZE {
+            self.message = Some(format!("The deck can't have fewer than {} cards", MIN_DECK_SIZE));
+            return false;
+        }
+        if *gold < SHOP_REMOVE_COST {
+            self.message = Some(format!("Removing a card costs {} gold", SHOP_REMOVE_COST));
+            return false;
+        }
+        *gold -= SHOP_REMOVE_COST;
+        collection.remove_card(&id);
+        let name = catalog.get(&id).map_or(id.clone(), |definition| definition.name.clone());
+        self.message = Some(format!("Removed a copy of {}", name));
+        self.refresh_cards(collection, catalog);
+        true
+    }
+
+    /// Melhora todas as cópias da carta selecionada
+    pub fn upgrade_selected(&mut self, gold: &mut u32, collection: &mut CardCollection, catalog: &CardCatalog) -> bool {
+        let Some(id) = self.selected_card_id().map(str::to_string) else {
+            return false;
+        };
+        if collection.is_upgraded(&id) {
+            self.message = Some("This card is already upgraded".to_string());
+            return false;
+        }
+        if *gold < SHOP_UPGRADE_COST {
+            self.message = Some(format!("Upgrading a card costs {} gold", SHOP_UPGRADE_COST));
+            return false;
+        }
+        *gold -= SHOP_UPGRADE_COST;
+        collection.upgrade_card(&id);
+        let name = catalog.get(&id).map_or(id.clone(), |definition| definition.name.clone());
+        self.message = Some(format!("Upgraded {}", name));
+        true
+    }
+}
@@ -3,7 +3,7 @@ use crate::state::battle_state::BattleState;
 use crate::deck::CardTextureManager;
 use super::components::*;
 
-pub fn draw_battle(battle: &BattleState, card_textures: &CardTextureManager, emoji_font: Option<&Font>, enemy_image: &str) {
+pub fn draw_battle(battle: &BattleState, card_textures: &CardTextureManager, emoji_font: Option<&Font>) {
     let font_size = 22.0;
     let margin = 20.0;
 
@@ -21,13 +21,14 @@ pub fn draw_battle(battle: &BattleState, card_textures: &CardTextureManager, emo
     );
 
     // 3. CENTRO: Inimigo com animações
-    draw_enemy_with_animation(battle, card_textures, enemy_image);
+    draw_enemy_with_animation(battle, card_textures, emoji_font);
 
     // 4. ACIMA DAS CARTAS: Informações do jogador
     draw_player_info_above_cards(battle, font_size, emoji_font);
 
     // 5. CENTRO-INFERIOR: Cartas da mão do jogador
-    draw_player_hand_with_animation(&battle.player.hand, battle, card_textures);
+    draw_player_hand_with_animation(&battle.engine.player.hand, battle, card_textures);
+    draw_card_piles(battle, card_textures);
 
     // 6. PARTE INFERIOR: Instruções
     draw_instructions(battle, font_size);
@@ -40,13 +41,18 @@ pub fn draw_battle(battle: &BattleState, card_textures: &CardTextureManager, emo
     // Desenhar log de batalha no canto direito
     draw_battle_log(battle, emoji_font);
     
-    // Efeito de slow motion se ativo
+    // Tooltip dos efeitos de status sob o mouse
+    draw_status_tooltip(battle, margin, emoji_font);
+
+    // Efeito de slow motion no golpe final ou na troca de fase do chefe
     if battle.is_final_blow {
-        draw_slow_motion_effect(battle);
+        draw_slow_motion_effect("💀 FINAL BLOW! 💀", battle.slow_motion_timer);
+    } else if let Some(banner) = &battle.phase_banner {
+        draw_slow_motion_effect(banner, battle.phase_banner_timer);
     }
 }
 
-fn draw_slow_motion_effect(battle: &BattleState) {
+fn draw_slow_motion_effect(text: &str, timer: f32) {
     let screen_width = screen_width();
     let screen_height = screen_height();
     
@@ -103,8 +109,7 @@ fn draw_slow_motion_effect(battle: &BattleState) {
         Color::new(1.0f32, 0.0f32, 0.0f32, border_alpha),
     );
     
-    // Text "FINAL BLOW!" in the center
-    let text = "💀 FINAL BLOW! 💀";
+    // Texto do efeito no centro
     let text_size = 48.0;
     let text_dims = measure_text(text, None, text_size as u16, 1.0);
     
@@ -127,7 +132,7 @@ fn draw_slow_motion_effect(battle: &BattleState) {
     );
     
     // Timer no canto
-    let timer_text = format!("{:.1}s", battle.slow_motion_timer);
+    let timer_text = format!("{:.1}s", timer);
     let timer_size = 24.0;
     draw_text(
         &timer_text,
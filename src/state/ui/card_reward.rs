@@ -0,0 +1,99 @@
+use macroquad::prelude::*;
+use crate::config::config::*;
+use crate::deck::CardTextureManager;
+use crate::state::card_reward_state::CardRewardState;
+use crate::state::ui::components::draw_text_with_emoji;
+
+const REWARD_CARD_SCALE: f32 = 1.5;
+const REWARD_CARD_SPACING: f32 = 40.0;
+
+fn reward_card_rect(index: usize, count: usize) -> Rect {
+    let width = CARD_WIDTH * REWARD_CARD_SCALE;
+    let height = CARD_HEIGHT * REWARD_CARD_SCALE;
+    let total_width = count as f32 * (width + REWARD_CARD_SPACING) - REWARD_CARD_SPACING;
+    let start_x = (screen_width() - total_width) / 2.0;
+    Rect::new(
+        start_x + index as f32 * (width + REWARD_CARD_SPACING),
+        screen_height() * 0.30,
+        width,
+        height,
+    )
+}
+
+pub fn draw_card_reward(reward: &CardRewardState, card_textures: &CardTextureManager, emoji_font: Option<&Font>) {
+    let screen_width = screen_width();
+    let screen_height = screen_height();
+
+    draw_rectangle(0.0, 0.0, screen_width, screen_height, Color::new(0.0, 0.0, 0.0, 0.7));
+
+    let title = "🎁 CHOOSE YOUR REWARD 🎁";
+    let title_size = 36.0;
+    let title_dims = measure_text(title, None, title_size as u16, 1.0);
+    draw_text_with_emoji(
+        title,
+        (screen_width - title_dims.width) / 2.0,
+        screen_height * 0.12,
+        title_size,
+        GOLD,
+        emoji_font,
+    );
+
+    let subtitle_dims = measure_text(&reward.subtitle, None, 20, 1.0);
+    draw_text(
+        &reward.subtitle,
+        (screen_width - subtitle_dims.width) / 2.0,
+        screen_height * 0.20,
+        20.0,
+        WHITE,
+    );
+
+    let (mouse_x, mouse_y) = mouse_position();
+    for (i, card) in reward.choices.iter().enumerate() {
+        let rect = reward_card_rect(i, reward.choices.len());
+        let is_selected = i == reward.selected_index;
+        let is_hovered = rect.contains(vec2(mouse_x, mouse_y));
+        let lift = if is_selected || is_hovered { 12.0 } else { 0.0 };
+
+        card_textures.draw_card(card, rect.x, rect.y - lift, rect.w, rect.h);
+
+        if is_selected {
+            for offset in 1..=3 {
+                let alpha = 0.4 - (offset as f32 * 0.1);
+                let spread = offset as f32 * 3.0;
+                draw_rectangle_lines(
+                    rect.x - spread,
+                    rect.y - lift - spread,
+                    rect.w + spread * 2.0,
+                    rect.h + spread * 2.0,
+                    2.0,
+                    Color::new(1.0, 0.84, 0.0, alpha),
+                );
+            }
+        }
+
+        let rarity_text = format!("{}  ({:?})", card.name, card.rarity);
+        let rarity_dims = measure_text(&rarity_text, None, 18, 1.0);
+        draw_text(
+            &rarity_text,
+            rect.x + (rect.w - rarity_dims.width) / 2.0,
+            rect.y + rect.h + 28.0,
+            18.0,
+            if is_selected { YELLOW } else { LIGHTGRAY },
+        );
+    }
+
+    let controls = "A/D or 1-3: Select | Enter or click: Take card | ESC: Skip";
+    let controls_size = 16.0;
+    let controls_dims = measure_text(controls, None, controls_size as u16, 1.0);
+    draw_text(
+        controls,
+        (screen_width - controls_dims.width) / 2.0,
+        screen_height * 0.92,
+        controls_size,
+        LIGHTGRAY,
+    );
+}
+
+pub fn get_clicked_reward_index(mouse_x: f32, mouse_y: f32, count: usize) -> Option<usize> {
+    (0..count).find(|&i| reward_card_rect(i, count).contains(vec2(mouse_x, mouse_y)))
+}
@@ -1,7 +1,10 @@
 use macroquad::prelude::*;
 use crate::state::battle_state::BattleState;
-use crate::deck::{Hand, CardTextureManager};
-use crate::effects::StatusEffect;
+use crate::deck::{Hand, CardTextureManager, CardType};
+use crate::battle_engine::{Combatant, EnemyIntent};
+use crate::effects::{ActiveStatus, StatusEffect, StatusTrigger};
+use crate::entity::Entity;
+use crate::status_registry::StatusDefinition;
 
 // Função auxiliar para desenhar texto com emojis
 pub fn draw_text_with_emoji(text: &str, x: f32, y: f32, font_size: f32, color: Color, emoji_font: Option<&Font>) {
@@ -108,6 +111,9 @@ pub fn draw_player_hand_with_animation(hand: &Hand, battle: &BattleState, card_t
             && mouse_y >= y
             && mouse_y <= y + card_height;
         card_textures.draw_card_scaled(card, x, y, card_width, card_height);
+        if battle.engine.turn.player_turn() && !battle.engine.turn.can_afford(card.cost) {
+            draw_rectangle(x, y, card_width, card_height, Color::new(0.0, 0.0, 0.0, 0.55));
+        }
         let number_text = format!("{}", i + 1);
         let text_x = x + card_width / 2.0 - measure_text(&number_text, None, 16, 1.0).width / 2.0;
         draw_text(&number_text, text_x, y - 5.0, 16.0, WHITE);
@@ -117,20 +123,104 @@ pub fn draw_player_hand_with_animation(hand: &Hand, battle: &BattleState, card_t
     }
 }
 
-pub fn draw_enemy_with_animation(battle: &BattleState, card_textures: &CardTextureManager, enemy_image: &str) {
-    let enemy_width = 225.0; 
-    let enemy_height = 300.0; 
-    let screen_width = screen_width();
-    let screen_height = screen_height();
-    let mut x = (screen_width - enemy_width) / 2.0;
-    let y = (screen_height - enemy_height) / 2.0 - 95.0;
-    if battle.enemy_shake_timer > 0.0 {
-        use ::rand::Rng;
-        let mut rng = ::rand::thread_rng();
-        let shake_x = rng.gen_range(-ENEMY_SHAKE_INTENSITY..ENEMY_SHAKE_INTENSITY);
-        x += shake_x;
+const PILE_WIDTH: f32 = 70.0;
+const PILE_HEIGHT: f32 = 105.0;
+
+/// Pilha de compra à esquerda da mão e descarte/exílio à direita, com as contagens
+pub fn draw_card_piles(battle: &BattleState, card_textures: &CardTextureManager) {
+    let piles = &battle.engine.piles;
+    let pile_y = screen_height() * 0.75 + 20.0;
+    let margin = 30.0;
+
+    let draw_x = margin;
+    if piles.draw.cards.is_empty() {
+        draw_rectangle_lines(draw_x, pile_y, PILE_WIDTH, PILE_HEIGHT, 2.0, DARKGRAY);
+    } else {
+        card_textures.draw_card_back(draw_x, pile_y, PILE_WIDTH, PILE_HEIGHT);
+    }
+    draw_text(&format!("Draw: {}", piles.draw.cards.len()), draw_x, pile_y + PILE_HEIGHT + 20.0, 18.0, WHITE);
+
+    let discard_x = screen_width() - PILE_WIDTH - margin;
+    match piles.discard.last() {
+        Some(card) => card_textures.draw_card(card, discard_x, pile_y, PILE_WIDTH, PILE_HEIGHT),
+        None => draw_rectangle_lines(discard_x, pile_y, PILE_WIDTH, PILE_HEIGHT, 2.0, DARKGRAY),
+    }
+    draw_text(&format!("Discard: {}", piles.discard.len()), discard_x - 10.0, pile_y + PILE_HEIGHT + 20.0, 18.0, WHITE);
+    if !piles.exhaust.is_empty() {
+        draw_text(&format!("Exhausted: {}", piles.exhaust.len()), discard_x - 10.0, pile_y - 8.0, 16.0, GRAY);
+    }
+}
+
+/// Área do sprite de cada inimigo; com mais de um, ficam lado a lado e menores, longe do log
+pub fn enemy_sprite_rect(index: usize, count: usize) -> Rect {
+    let base_width = 225.0;
+    let base_height = 300.0;
+    let scale = match count {
+        0 | 1 => 1.0,
+        2 => 0.7,
+        _ => 0.6,
+    };
+    let width = base_width * scale;
+    let height = base_height * scale;
+    let bottom = (screen_height() - base_height) / 2.0 - 95.0 + base_height;
+    let center_x = if count > 1 { (screen_width() - 390.0) / 2.0 } else { screen_width() / 2.0 };
+    let spacing = 30.0;
+    let total_width = count.max(1) as f32 * width + count.saturating_sub(1) as f32 * spacing;
+    let x = center_x - total_width / 2.0 + index as f32 * (width + spacing);
+    Rect::new(x, bottom - height, width, height)
+}
+
+pub fn draw_enemy_with_animation(battle: &BattleState, card_textures: &CardTextureManager, emoji_font: Option<&Font>) {
+    let enemies = &battle.engine.enemies;
+    let multiple = enemies.len() > 1;
+    for (index, enemy) in enemies.iter().enumerate() {
+        if !battle.engine.is_alive(index) {
+            continue;
+        }
+        let rect = enemy_sprite_rect(index, enemies.len());
+        let mut x = rect.x;
+        if battle.enemy_shake_timer > 0.0 && battle.shaken_enemy == index {
+            use ::rand::Rng;
+            let mut rng = ::rand::thread_rng();
+            let shake_x = rng.gen_range(-ENEMY_SHAKE_INTENSITY..ENEMY_SHAKE_INTENSITY);
+            x += shake_x;
+        }
+        card_textures.draw_enemy(x, rect.y, rect.w, rect.h, &enemy.image_path);
+
+        // Com vários inimigos, cada um mostra a própria vida e intenção; o alvo ganha contorno
+        if multiple {
+            if battle.engine.target() == index {
+                draw_rectangle_lines(rect.x - 4.0, rect.y - 4.0, rect.w + 8.0, rect.h + 8.0, 3.0, YELLOW);
+            }
+            draw_health_bar(
+                rect.x,
+                rect.y - 14.0,
+                rect.w,
+                8.0,
+                enemy.health,
+                enemy.max_health,
+                RED,
+                Color::new(0.3, 0.0, 0.0, 1.0),
+            );
+            draw_enemy_intent(battle, index, rect.x, rect.y - 20.0, 16.0, emoji_font);
+            draw_status_icons(battle, Combatant::Enemy(index), 0.0, emoji_font);
+        }
     }
-    card_textures.draw_enemy(x, y, enemy_width, enemy_height, enemy_image);
+}
+
+fn format_combat_stats(entity: &dyn Entity) -> String {
+    let format_stat = |label: &str, base: u32, total: u32| {
+        if total > base {
+            format!("{} {} (+{})", label, total, total - base)
+        } else {
+            format!("{} {}", label, total)
+        }
+    };
+    format!(
+        "{}   {}",
+        format_stat("ATK", entity.base_attack(), entity.attack()),
+        format_stat("DEF", entity.base_defense(), entity.defense())
+    )
 }
 
 pub fn draw_health_bar(x: f32, y: f32, width: f32, height: f32, current: u32, max: u32, fill_color: Color, bg_color: Color) {
@@ -147,27 +237,75 @@ pub fn draw_health_bar(x: f32, y: f32, width: f32, height: f32, current: u32, ma
     draw_text(&health_text, text_x, text_y, text_size, WHITE);
 }
 
+/// Ícone e texto da próxima jogada do inimigo; o ataque que segue a carta aparece no fim
+fn format_intent(intent: &EnemyIntent, battle: &BattleState) -> String {
+    let attack = if intent.hits > 1 {
+        format!("⚔️ {}x{}", intent.damage, intent.hits)
+    } else {
+        format!("⚔️ {}", intent.damage)
+    };
+    let action = match &intent.card.card_type {
+        CardType::AttackBasic(_) | CardType::AttackStrong(_) | CardType::Strike(_) | CardType::MultiHit(_, _) => {
+            return attack;
+        }
+        CardType::Pierce(_) => return format!("🗡️ {} (pierce)", intent.damage),
+        CardType::Defense(amount) => format!("🛡️ +{} DEF", amount),
+        CardType::Block(amount) => return format!("🛡️ Block {}", amount),
+        CardType::Heal(_) => "💚 Heal".to_string(),
+        CardType::Poison(_) => "☠️ Poison".to_string(),
+        CardType::Burn(_) => "🔥 Burn".to_string(),
+        CardType::ApplyStatus(effect, _) => match battle.engine.statuses().get(*effect) {
+            Some(definition) => format!("{} {}", definition.icon, definition.name),
+            None => format!("{:?}", effect),
+        },
+    };
+    format!("{}  {}", action, attack)
+}
+
+/// Próxima jogada anunciada pelo inimigo, ao lado do nome
+fn draw_enemy_intent(battle: &BattleState, index: usize, x: f32, y: f32, font_size: f32, emoji_font: Option<&Font>) {
+    if battle.engine.is_stunned(Combatant::Enemy(index)) {
+        draw_text_with_emoji("Intent: 💫 Stunned", x, y, font_size, PURPLE, emoji_font);
+        return;
+    }
+    let Some(intent) = battle.engine.enemy_intent(index) else {
+        return;
+    };
+    let color = if intent.hits > 0 { ORANGE } else { SKYBLUE };
+    draw_text_with_emoji(&format!("Intent: {}", format_intent(&intent, battle)), x, y, font_size, color, emoji_font);
+}
+
+/// Painel do inimigo em foco: o alvo atual quando há mais de um
 pub fn draw_enemy_info(battle: &BattleState, margin: f32, font_size: f32, emoji_font: Option<&Font>) {
     let line_height = 25.0;
+    let target = battle.engine.target();
+    let enemy = &battle.engine.enemies[target];
+    let name_text = if battle.engine.enemies.len() > 1 {
+        format!("🎯 {}", enemy.name)
+    } else {
+        format!("👾 {}", enemy.name)
+    };
     draw_text_with_emoji(
-        &format!("👾 {}", battle.enemy.name),
+        &name_text,
         margin,
         margin + line_height,
         font_size + 2.0,
         RED,
         emoji_font,
     );
+    let name_width = measure_text(&name_text, None, (font_size + 2.0) as u16, 1.0).width;
+    draw_enemy_intent(battle, target, margin + name_width + 30.0, margin + line_height, font_size - 2.0, emoji_font);
     draw_health_bar(
         margin,
         margin + line_height + 10.0,
         300.0,
         18.0,
-        battle.enemy.health,
-        battle.enemy.max_health,
+        enemy.health,
+        enemy.max_health,
         RED,
         Color::new(0.3, 0.0, 0.0, 1.0),
     );
-    let enemy_stats = format!("ATK {}   DEF {}", battle.enemy.attack, battle.enemy.defense);
+    let enemy_stats = format_combat_stats(enemy);
     draw_text(
         &enemy_stats,
         margin,
@@ -175,47 +313,27 @@ pub fn draw_enemy_info(battle: &BattleState, margin: f32, font_size: f32, emoji_
         font_size - 2.0,
         LIGHTGRAY,
     );
-    if let Some(poison_duration) = battle.enemy.status_effects.get(&StatusEffect::Poison) {
-        let pulse = (get_time() * 4.0).sin() * 0.4 + 0.6;
-        let poison_color = Color::new(0.6, 1.0, 0.6, pulse as f32);
-        draw_text_with_emoji(
-            &format!("☠️ ENVENENADO ({})", poison_duration),
-            margin + 150.0,
-            margin + line_height + 35.0,
-            font_size - 2.0,
-            poison_color,
-            emoji_font,
-        );
-    }
-    if let Some(burn_duration) = battle.enemy.status_effects.get(&StatusEffect::Burn) {
-        let pulse = (get_time() * 4.0).sin() * 0.4 + 0.6;
-        let burn_color = Color::new(1.0, 0.3, 0.3, pulse as f32);
-        draw_text_with_emoji(
-            &format!("🔥 QUEIMADO ({})", burn_duration),
-            margin + 150.0,
-            margin + line_height + 50.0,
-            font_size - 2.0,
-            burn_color,
-            emoji_font,
-        );
+    // Com vários inimigos, os ícones ficam no sprite de cada um
+    if battle.engine.enemies.len() == 1 {
+        draw_status_icons(battle, Combatant::Enemy(target), margin, emoji_font);
     }
 }
 
 pub fn draw_player_info(battle: &BattleState, font_size: f32, emoji_font: Option<&Font>) {
     let info_y = screen_height() * 0.8;
     let margin = 50.0;
-    draw_text_with_emoji(&format!("🧙 {}", battle.player.name), margin, info_y, font_size + 2.0, BLUE, emoji_font);
+    draw_text_with_emoji(&format!("🧙 {}", battle.engine.player.name), margin, info_y, font_size + 2.0, BLUE, emoji_font);
     draw_health_bar(
         margin,
         info_y + 10.0,
         300.0,
         18.0,
-        battle.player.health,
-        battle.player.max_health,
+        battle.engine.player.health,
+        battle.engine.player.max_health,
         GREEN,
         Color::new(0.0, 0.3, 0.0, 1.0),
     );
-    let player_stats = format!("ATK {}   DEF {}", battle.player.attack, battle.player.defense);
+    let player_stats = format_combat_stats(&battle.engine.player);
     draw_text(
         &player_stats,
         margin,
@@ -223,11 +341,11 @@ pub fn draw_player_info(battle: &BattleState, font_size: f32, emoji_font: Option
         font_size - 2.0,
         LIGHTGRAY,
     );
-    if let Some(poison_duration) = battle.player.status_effects.get(&StatusEffect::Poison) {
+    if let Some(poison_duration) = battle.engine.player.status_effects.get(&StatusEffect::Poison) {
         let pulse = (get_time() * 4.0).sin() * 0.4 + 0.6;
         let poison_color = Color::new(0.6, 1.0, 0.6, pulse as f32);
         draw_text_with_emoji(
-            &format!("☠️ POISONED ({})", poison_duration),
+            &format!("☠️ POISONED ({})", poison_duration.duration),
             margin + 150.0,
             info_y + 35.0,
             font_size - 2.0,
@@ -235,11 +353,11 @@ pub fn draw_player_info(battle: &BattleState, font_size: f32, emoji_font: Option
             emoji_font,
         );
     }
-    if let Some(burn_duration) = battle.player.status_effects.get(&StatusEffect::Burn) {
+    if let Some(burn_duration) = battle.engine.player.status_effects.get(&StatusEffect::Burn) {
         let pulse = (get_time() * 4.0).sin() * 0.4 + 0.6;
         let burn_color = Color::new(1.0, 0.3, 0.3, pulse as f32);
         draw_text_with_emoji(
-            &format!("🔥 BURNED ({})", burn_duration),
+            &format!("🔥 BURNED ({})", burn_duration.duration),
             margin + 150.0,
             info_y + 50.0,
             font_size - 2.0,
@@ -253,20 +371,20 @@ pub fn draw_player_info_above_cards(battle: &BattleState, font_size: f32, emoji_
     let info_y = screen_height() * 0.55;
     let margin = 50.0;
     
-    draw_text_with_emoji(&format!("🧙 {}", battle.player.name), margin, info_y, font_size + 2.0, BLUE, emoji_font);
+    draw_text_with_emoji(&format!("🧙 {}", battle.engine.player.name), margin, info_y, font_size + 2.0, BLUE, emoji_font);
     
     draw_health_bar(
         margin,
         info_y + 10.0,
         300.0,
         18.0,
-        battle.player.health,
-        battle.player.max_health,
+        battle.engine.player.health,
+        battle.engine.player.max_health,
         GREEN,
         Color::new(0.0, 0.3, 0.0, 1.0),
     );
     
-    let player_stats = format!("ATK {}   DEF {}", battle.player.attack, battle.player.defense);
+    let player_stats = format_combat_stats(&battle.engine.player);
     draw_text(
         &player_stats,
         margin,
@@ -274,38 +392,206 @@ pub fn draw_player_info_above_cards(battle: &BattleState, font_size: f32, emoji_
         font_size - 2.0,
         LIGHTGRAY,
     );
-    
-    if let Some(poison_duration) = battle.player.status_effects.get(&StatusEffect::Poison) {
-        let pulse = (get_time() * 4.0).sin() * 0.4 + 0.6;
-        let poison_color = Color::new(0.6, 1.0, 0.6, pulse as f32);
-        draw_text_with_emoji(
-            &format!("☠️ ENVENENADO ({})", poison_duration),
-            margin + 150.0,
-            info_y + 35.0,
-            font_size - 2.0,
-            poison_color,
-            emoji_font,
-        );
+
+    draw_status_icons(battle, Combatant::Player, margin, emoji_font);
+    draw_energy(battle, margin + 320.0, info_y + 54.0, font_size, emoji_font);
+}
+
+const STATUS_ICON_SIZE: f32 = 28.0;
+const STATUS_ICON_SPACING: f32 = 4.0;
+/// Espaço entre linhas de ícones, com lugar para a duração escrita embaixo de cada um
+const STATUS_ICON_ROW_SPACING: f32 = 14.0;
+
+/// Posição de cada ícone de status: em fileira logo à direita da barra de vida, ou,
+/// com vários inimigos, no alto do sprite de cada um, quebrando a linha na largura dele
+fn status_icon_rects(battle: &BattleState, target: Combatant, margin: f32) -> Vec<(StatusEffect, Rect)> {
+    let count = battle.engine.enemies.len();
+    let (x, y, per_row) = match target {
+        Combatant::Enemy(index) if count > 1 => {
+            let rect = enemy_sprite_rect(index, count);
+            let per_row = ((rect.w + STATUS_ICON_SPACING) / (STATUS_ICON_SIZE + STATUS_ICON_SPACING)).max(1.0) as usize;
+            (rect.x + 4.0, rect.y + 4.0, per_row)
+        }
+        Combatant::Enemy(_) => (margin + 312.0, margin + 30.0, usize::MAX),
+        Combatant::Player => (50.0 + 312.0, screen_height() * 0.55 + 5.0, usize::MAX),
+    };
+    battle
+        .engine
+        .entity(target)
+        .status_effects()
+        .keys()
+        .enumerate()
+        .map(|(i, effect)| {
+            let icon_x = x + (i % per_row) as f32 * (STATUS_ICON_SIZE + STATUS_ICON_SPACING);
+            let icon_y = y + (i / per_row) as f32 * (STATUS_ICON_SIZE + STATUS_ICON_ROW_SPACING);
+            (*effect, Rect::new(icon_x, icon_y, STATUS_ICON_SIZE, STATUS_ICON_SIZE))
+        })
+        .collect()
+}
+
+/// Ícones dos efeitos ativos com a duração restante e, se houver, as camadas
+fn draw_status_icons(battle: &BattleState, target: Combatant, margin: f32, emoji_font: Option<&Font>) {
+    let statuses = battle.engine.statuses();
+    let active = battle.engine.entity(target).status_effects();
+    for (effect, rect) in status_icon_rects(battle, target, margin) {
+        let (Some(definition), Some(status)) = (statuses.get(effect), active.get(&effect)) else {
+            continue;
+        };
+        let background = if definition.beneficial {
+            Color::new(0.1, 0.35, 0.15, 0.9)
+        } else {
+            Color::new(0.4, 0.1, 0.1, 0.9)
+        };
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, background);
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.5, LIGHTGRAY);
+        draw_text_with_emoji(&definition.icon, rect.x + 4.0, rect.y + rect.h - 7.0, 20.0, WHITE, emoji_font);
+
+        let duration_text = status.duration.to_string();
+        let duration_dims = measure_text(&duration_text, None, 14, 1.0);
+        draw_text(&duration_text, rect.x + rect.w - duration_dims.width - 1.0, rect.y + rect.h + 2.0, 14.0, YELLOW);
+        if status.stacks > 1 {
+            draw_text(&format!("x{}", status.stacks), rect.x + 1.0, rect.y + 9.0, 12.0, ORANGE);
+        }
     }
-    if let Some(burn_duration) = battle.player.status_effects.get(&StatusEffect::Burn) {
-        let pulse = (get_time() * 4.0).sin() * 0.4 + 0.6;
-        let burn_color = Color::new(1.0, 0.3, 0.3, pulse as f32);
-        draw_text_with_emoji(
-            &format!("🔥 QUEIMADO ({})", burn_duration),
-            margin + 150.0,
-            info_y + 50.0,
-            font_size - 2.0,
-            burn_color,
-            emoji_font,
-        );
+}
+
+/// Linhas do tooltip: o que o efeito faz e quanto vai causar no próximo disparo
+fn status_tooltip_lines(definition: &StatusDefinition, status: &ActiveStatus) -> Vec<String> {
+    let when = match definition.trigger {
+        StatusTrigger::TurnStart => "at the start of the turn",
+        StatusTrigger::TurnEnd => "at the end of the turn",
+    };
+    let mut lines = vec![definition.description.clone()];
+    if definition.tick_damage > 0 {
+        lines.push(format!("Next tick: {} damage {}", definition.tick_damage_for(status), when));
+    }
+    if definition.tick_heal > 0 {
+        lines.push(format!("Next tick: heals {} {}", definition.tick_heal_for(status), when));
+    }
+    if definition.damage_dealt_multiplier != 1.0 {
+        lines.push(format!("Damage dealt x{:.2}", definition.damage_dealt_multiplier));
+    }
+    if definition.damage_taken_multiplier != 1.0 {
+        lines.push(format!("Damage taken x{:.2}", definition.damage_taken_multiplier));
+    }
+    if definition.skips_turn {
+        lines.push("Skips the next turn".to_string());
+    }
+    if definition.thorns > 0 {
+        lines.push(format!("Returns {} damage to attackers", definition.thorns_for(status)));
+    }
+    if status.stacks > 1 {
+        lines.push(format!("{} turns left, {} stacks", status.duration, status.stacks));
+    } else {
+        lines.push(format!("{} turns left", status.duration));
+    }
+    lines
+}
+
+/// Tooltip do ícone de status sob o mouse; desenhado por último para ficar por cima
+pub fn draw_status_tooltip(battle: &BattleState, margin: f32, emoji_font: Option<&Font>) {
+    let mouse = Vec2::from(mouse_position());
+    let mut targets = battle.engine.living_enemies().map(Combatant::Enemy).chain(std::iter::once(Combatant::Player));
+    let hovered = targets.find_map(|target| {
+        status_icon_rects(battle, target, margin)
+            .into_iter()
+            .find(|(_, rect)| rect.contains(mouse))
+            .map(|(effect, _)| (target, effect))
+    });
+    let Some((target, effect)) = hovered else {
+        return;
+    };
+    let (Some(definition), Some(status)) = (
+        battle.engine.statuses().get(effect),
+        battle.engine.entity(target).status_effects().get(&effect),
+    ) else {
+        return;
+    };
+
+    let title = format!("{} {}", definition.icon, definition.name);
+    let lines = status_tooltip_lines(definition, status);
+    let text_size = 16.0;
+    let line_height = 20.0;
+    let padding = 8.0;
+    let width = lines
+        .iter()
+        .chain(std::iter::once(&title))
+        .map(|line| measure_text(line, None, text_size as u16, 1.0).width)
+        .fold(0.0, f32::max)
+        + padding * 2.0;
+    let height = (lines.len() + 1) as f32 * line_height + padding * 2.0;
+    let x = (mouse.x + 16.0).min(screen_width() - width - 5.0);
+    let y = (mouse.y + 16.0).min(screen_height() - height - 5.0);
+
+    draw_rectangle(x, y, width, height, Color::new(0.05, 0.05, 0.1, 0.95));
+    draw_rectangle_lines(x, y, width, height, 2.0, LIGHTGRAY);
+    let title_color = if definition.beneficial { GREEN } else { ORANGE };
+    draw_text_with_emoji(&title, x + padding, y + padding + 14.0, text_size + 2.0, title_color, emoji_font);
+    for (i, line) in lines.iter().enumerate() {
+        draw_text(line, x + padding, y + padding + 14.0 + (i + 1) as f32 * line_height, text_size, WHITE);
     }
 }
 
+/// Energia do turno do jogador, em número e em marcadores
+fn draw_energy(battle: &BattleState, x: f32, y: f32, font_size: f32, emoji_font: Option<&Font>) {
+    let turn = &battle.engine.turn;
+    let energy_text = format!("⚡ {}/{}", turn.energy, turn.max_energy);
+    draw_text_with_emoji(&energy_text, x, y, font_size, SKYBLUE, emoji_font);
+
+    let pip_radius = 6.0;
+    let pips_x = x + measure_text(&energy_text, None, font_size as u16, 1.0).width + 16.0;
+    for i in 0..turn.max_energy {
+        let center_x = pips_x + i as f32 * (pip_radius * 2.0 + 4.0);
+        let center_y = y - font_size / 3.0;
+        if i < turn.energy {
+            draw_circle(center_x, center_y, pip_radius, SKYBLUE);
+        }
+        draw_circle_lines(center_x, center_y, pip_radius, 1.5, WHITE);
+    }
+}
+
+fn end_turn_button_rect() -> Rect {
+    let width = 140.0;
+    let height = 36.0;
+    Rect::new(screen_width() - width - 50.0, screen_height() * 0.68 - height + 8.0, width, height)
+}
+
+pub fn is_end_turn_button_clicked(mouse_x: f32, mouse_y: f32) -> bool {
+    end_turn_button_rect().contains(vec2(mouse_x, mouse_y))
+}
+
+fn draw_end_turn_button(font_size: f32) {
+    let rect = end_turn_button_rect();
+    let (mouse_x, mouse_y) = mouse_position();
+    let color = if rect.contains(vec2(mouse_x, mouse_y)) {
+        Color::new(0.3, 0.3, 0.6, 1.0)
+    } else {
+        Color::new(0.2, 0.2, 0.4, 1.0)
+    };
+    draw_rectangle(rect.x, rect.y, rect.w, rect.h, color);
+    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, WHITE);
+
+    let text = "END TURN";
+    let text_dims = measure_text(text, None, font_size as u16, 1.0);
+    draw_text(
+        text,
+        rect.x + (rect.w - text_dims.width) / 2.0,
+        rect.y + (rect.h + text_dims.offset_y) / 2.0,
+        font_size,
+        WHITE,
+    );
+}
+
 pub fn draw_instructions(battle: &BattleState, font_size: f32) {
     let instructions_y = screen_height() * 0.68;
-    if battle.turn.player_turn() {
+    if battle.engine.turn.player_turn() {
         if !battle.waiting_for_cooldown {
-            let instructions = "Choose a card (1-5 or click)";
+            draw_end_turn_button(font_size);
+            let instructions = if battle.engine.enemies.len() > 1 {
+                "Choose cards (1-5 or click) | Tab/Arrows: Target | E/Space: End turn"
+            } else {
+                "Choose cards (1-5 or click) | E/Space: End turn"
+            };
             let screen_width = screen_width();
             let inst_dims = measure_text(instructions, None, font_size as u16, 1.0);
             draw_text(
@@ -0,0 +1,162 @@
+use macroquad::prelude::*;
+use crate::card_catalog::CardCatalog;
+use crate::config::config::*;
+use crate::deck::CardTextureManager;
+use crate::state::deck_editor_state::DeckEditorState;
+use crate::state::ui::components::draw_text_with_emoji;
+
+const ROW_HEIGHT: f32 = 54.0;
+const ROW_WIDTH: f32 = 460.0;
+const BUTTON_SIZE: f32 = 32.0;
+
+/// Botões de cada linha do editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckEditorButton {
+    Remove,
+    Add,
+}
+
+fn rows_origin() -> (f32, f32) {
+    (screen_width() * 0.05, screen_height() * 0.16)
+}
+
+fn button_rects(row_x: f32, row_y: f32) -> [(DeckEditorButton, Rect); 2] {
+    let button_y = row_y + (ROW_HEIGHT - BUTTON_SIZE) / 2.0;
+    let add_x = row_x + ROW_WIDTH - BUTTON_SIZE - 10.0;
+    let remove_x = add_x - BUTTON_SIZE - 8.0;
+    [
+        (DeckEditorButton::Remove, Rect::new(remove_x, button_y, BUTTON_SIZE, BUTTON_SIZE)),
+        (DeckEditorButton::Add, Rect::new(add_x, button_y, BUTTON_SIZE, BUTTON_SIZE)),
+    ]
+}
+
+pub fn draw_deck_editor(editor: &DeckEditorState, catalog: &CardCatalog, card_textures: &CardTextureManager, emoji_font: Option<&Font>) {
+    let screen_width = screen_width();
+    let screen_height = screen_height();
+
+    let title = "🃏 DECK EDITOR 🃏";
+    let title_size = 36.0;
+    let title_dims = measure_text(title, None, title_size as u16, 1.0);
+    draw_text_with_emoji(
+        title,
+        (screen_width - title_dims.width) / 2.0,
+        screen_height * 0.08,
+        title_size,
+        GOLD,
+        emoji_font,
+    );
+
+    let deck_size = editor.collection.deck_size();
+    let size_ok = (MIN_DECK_SIZE..=MAX_DECK_SIZE).contains(&deck_size);
+    let size_text = format!("Deck: {}/{} cards (minimum {})", deck_size, MAX_DECK_SIZE, MIN_DECK_SIZE);
+    let size_dims = measure_text(&size_text, None, 20, 1.0);
+    draw_text(
+        &size_text,
+        (screen_width - size_dims.width) / 2.0,
+        screen_height * 0.12,
+        20.0,
+        if size_ok { LIME } else { RED },
+    );
+
+    let (row_x, start_y) = rows_origin();
+    let (mouse_x, mouse_y) = mouse_position();
+
+    for (i, id) in editor.card_ids.iter().enumerate() {
+        let Some(definition) = catalog.get(id) else {
+            continue;
+        };
+        let row_y = start_y + i as f32 * ROW_HEIGHT;
+        let is_selected = i == editor.selected_index;
+
+        let bg_color = if is_selected {
+            Color::new(0.3, 0.3, 0.6, 0.9)
+        } else {
+            Color::new(0.2, 0.2, 0.4, 0.9)
+        };
+        draw_rectangle(row_x, row_y, ROW_WIDTH, ROW_HEIGHT - 4.0, bg_color);
+        draw_rectangle_lines(row_x, row_y, ROW_WIDTH, ROW_HEIGHT - 4.0, if is_selected { 3.0 } else { 1.0 }, if is_selected { YELLOW } else { GRAY });
+
+        let thumb_height = ROW_HEIGHT - 10.0;
+        let thumb_width = thumb_height * CARD_WIDTH / CARD_HEIGHT;
+        card_textures.draw_card(&definition.to_card(), row_x + 6.0, row_y + 3.0, thumb_width, thumb_height);
+
+        let text_x = row_x + thumb_width + 16.0;
+        draw_text(&definition.name, text_x, row_y + 20.0, 20.0, WHITE);
+
+        let copies_text = format!(
+            "In deck: {}  |  Limit: {}  |  Owned: {}",
+            editor.collection.deck_copies(id),
+            editor.collection.copy_limit(catalog, id),
+            editor.collection.owned_copies(id)
+        );
+        draw_text(&copies_text, text_x, row_y + 40.0, 14.0, LIGHTGRAY);
+
+        for (button, rect) in button_rects(row_x, row_y) {
+            let hovered = rect.contains(vec2(mouse_x, mouse_y));
+            let (label, color) = match button {
+                DeckEditorButton::Remove => ("-", if hovered { RED } else { MAROON }),
+                DeckEditorButton::Add => ("+", if hovered { LIME } else { DARKGREEN }),
+            };
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, color);
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, WHITE);
+            let label_dims = measure_text(label, None, 28, 1.0);
+            draw_text(label, rect.x + (rect.w - label_dims.width) / 2.0, rect.y + 24.0, 28.0, WHITE);
+        }
+    }
+
+    if let Some(definition) = editor.selected_card_id().and_then(|id| catalog.get(id)) {
+        let preview_width = CARD_WIDTH * 1.8;
+        let preview_height = CARD_HEIGHT * 1.8;
+        let preview_x = screen_width * 0.95 - preview_width;
+        let preview_y = start_y;
+        card_textures.draw_card(&definition.to_card(), preview_x, preview_y, preview_width, preview_height);
+
+        let rarity_text = format!("{:?}", definition.rarity);
+        let rarity_dims = measure_text(&rarity_text, None, 18, 1.0);
+        draw_text(
+            &rarity_text,
+            preview_x + (preview_width - rarity_dims.width) / 2.0,
+            preview_y + preview_height + 24.0,
+            18.0,
+            ORANGE,
+        );
+    }
+
+    if let Some(message) = &editor.message {
+        let message_dims = measure_text(message, None, 18, 1.0);
+        draw_text(
+            message,
+            (screen_width - message_dims.width) / 2.0,
+            screen_height * 0.90,
+            18.0,
+            ORANGE,
+        );
+    }
+
+    let controls = "W/S: Select | A/D or -/+: Remove/Add | Enter: Save | ESC: Discard changes";
+    let controls_size = 16.0;
+    let controls_dims = measure_text(controls, None, controls_size as u16, 1.0);
+    draw_text(
+        controls,
+        (screen_width - controls_dims.width) / 2.0,
+        screen_height * 0.95,
+        controls_size,
+        LIGHTGRAY,
+    );
+}
+
+/// Devolve a linha e o botão sob o mouse, se houver
+pub fn get_clicked_deck_editor_button(mouse_x: f32, mouse_y: f32, row_count: usize) -> Option<(usize, DeckEditorButton)> {
+    let (row_x, start_y) = rows_origin();
+
+    for i in 0..row_count {
+        let row_y = start_y + i as f32 * ROW_HEIGHT;
+        for (button, rect) in button_rects(row_x, row_y) {
+            if rect.contains(vec2(mouse_x, mouse_y)) {
+                return Some((i, button));
+            }
+        }
+    }
+
+    None
+}
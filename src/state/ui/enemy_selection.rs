@@ -3,7 +3,14 @@ use crate::state::game_state::EnemyInfo;
 use crate::state::ui::components::draw_text_with_emoji;
 use crate::player::Player;
 
-pub fn draw_enemy_selection(enemies: &Vec<EnemyInfo>, selected_index: usize, emoji_font: Option<&Font>, player: Option<&Player>, show_instructions: bool) {
+/// Ouro do jogador no canto superior direito
+pub fn draw_gold_counter(gold: u32, emoji_font: Option<&Font>) {
+    let text = format!("💰 {} gold", gold);
+    let text_dims = measure_text(&text, None, 20, 1.0);
+    draw_text_with_emoji(&text, screen_width() - text_dims.width - 30.0, 30.0, 20.0, GOLD, emoji_font);
+}
+
+pub fn draw_enemy_selection(enemies: &Vec<EnemyInfo>, selected_index: usize, emoji_font: Option<&Font>, player: Option<&Player>, show_instructions: bool, seed_input: &str, is_editing_seed: bool) {
     let screen_width = screen_width();
     let screen_height = screen_height();
 
@@ -92,7 +99,7 @@ pub fn draw_enemy_selection(enemies: &Vec<EnemyInfo>, selected_index: usize, emo
             LIGHTGRAY,
         );
 
-        let controls = "ESC: Main Menu | Q: Quit Game | Shift+R: Reset Progress";
+        let controls = "E: Edit Deck | S: Shop | ESC: Main Menu | Q: Quit Game | Shift+R: Reset Progress";
         let controls_size = 14.0;
         let controls_dims = measure_text(controls, None, controls_size as u16, 1.0);
         draw_text(
@@ -110,6 +117,23 @@ pub fn draw_enemy_selection(enemies: &Vec<EnemyInfo>, selected_index: usize, emo
         draw_text_with_emoji(help_text, help_x, screen_height * 0.22, help_size, LIGHTGRAY, emoji_font);
     }
 
+    let seed_text = if is_editing_seed {
+        format!("Seed: {}|  (Enter to confirm, ESC to clear)", seed_input)
+    } else if seed_input.is_empty() {
+        "Seed: random  (Tab to type one)".to_string()
+    } else {
+        format!("Seed: {}  (Tab to edit)", seed_input)
+    };
+    let seed_size = 16.0;
+    let seed_dims = measure_text(&seed_text, None, seed_size as u16, 1.0);
+    draw_text(
+        &seed_text,
+        (screen_width - seed_dims.width) / 2.0,
+        screen_height * 0.30,
+        seed_size,
+        if is_editing_seed { YELLOW } else { LIGHTGRAY },
+    );
+
     let card_width = 200.0;
     let card_height = 280.0;
     let card_spacing = 30.0;
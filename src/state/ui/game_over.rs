@@ -1,6 +1,6 @@
 use macroquad::prelude::*;
 
-pub fn draw_game_over(winner: &Option<String>) {
+pub fn draw_game_over(winner: &Option<String>, seed: Option<u64>) {
     let screen_width = screen_width();
     let screen_height = screen_height();
 
@@ -75,6 +75,19 @@ pub fn draw_game_over(winner: &Option<String>) {
         );
     }
 
+    if let Some(seed) = seed {
+        let seed_text = format!("Seed: {}", seed);
+        let seed_size = 14.0;
+        let seed_dims = measure_text(&seed_text, None, seed_size as u16, 1.0);
+        draw_text(
+            &seed_text,
+            panel_x + (panel_width - seed_dims.width) / 2.0,
+            panel_y + 180.0,
+            seed_size,
+            LIGHTGRAY,
+        );
+    }
+
     let button_width = 200.0;
     let button_height = 40.0;
     let button_x = panel_x + (panel_width - button_width) / 2.0;
@@ -112,7 +125,7 @@ pub fn draw_game_over(winner: &Option<String>) {
         WHITE,
     );
 
-    let instructions = "ESC or click the button to continue";
+    let instructions = "ESC or click the button to continue | R: Watch replay";
     let inst_size = 14.0;
     let inst_dims = measure_text(instructions, None, inst_size as u16, 1.0);
     draw_text(
@@ -1,8 +1,9 @@
 use macroquad::prelude::*;
+use crate::error::GameError;
 use crate::state::menu::MenuSelection;
 use crate::state::ui::components::draw_text_with_emoji;
 
-pub fn draw_menu(selection: &MenuSelection, player_name: &str, is_editing_name: bool, emoji_font: Option<&Font>) {
+pub fn draw_menu(selection: &MenuSelection, player_name: &str, is_editing_name: bool, save_error: Option<&GameError>, emoji_font: Option<&Font>) {
     let screen_width = screen_width();
     let screen_height = screen_height();
 
@@ -83,133 +84,88 @@ pub fn draw_menu(selection: &MenuSelection, player_name: &str, is_editing_name:
         LIGHTGRAY,
     );
 
-    let start_text = match selection {
-        MenuSelection::Start => ">> Start <<",
-        MenuSelection::SoundSettings => "Start",
-        MenuSelection::Quit => "Start",
-    };
-    let start_color = match selection {
-        MenuSelection::Start => LIME,
-        MenuSelection::SoundSettings => GRAY,
-        MenuSelection::Quit => GRAY,
-    };
-
-    let sound_text = match selection {
-        MenuSelection::Start => "Sound Settings",
-        MenuSelection::SoundSettings => ">> Sound Settings <<",
-        MenuSelection::Quit => "Sound Settings",
-    };
-    let sound_color = match selection {
-        MenuSelection::Start => GRAY,
-        MenuSelection::SoundSettings => BLUE,
-        MenuSelection::Quit => GRAY,
-    };
-
-    let quit_text = match selection {
-        MenuSelection::Start => "Quit",
-        MenuSelection::SoundSettings => "Quit",
-        MenuSelection::Quit => ">> Quit <<",
-    };
-    let quit_color = match selection {
-        MenuSelection::Start => GRAY,
-        MenuSelection::SoundSettings => GRAY,
-        MenuSelection::Quit => RED,
-    };
-
     let option_size = 30.0;
-    let start_dims = measure_text(start_text, None, option_size as u16, 1.0);
-    let sound_dims = measure_text(sound_text, None, option_size as u16, 1.0);
-    let quit_dims = measure_text(quit_text, None, option_size as u16, 1.0);
-
-    let start_x = (screen_width - start_dims.width) / 2.0;
-    let start_y = screen_height * 0.6;
-    if matches!(selection, MenuSelection::Start) {
-        draw_rectangle(
-            start_x - 10.0,
-            start_y - 25.0,
-            start_dims.width + 20.0,
-            option_size + 10.0,
-            Color::new(0.0, 1.0, 0.0, 0.1),
-        );
+    for (index, option) in MenuSelection::ALL.into_iter().enumerate() {
+        let is_selected = option == *selection;
+        let text = if is_selected {
+            format!(">> {} <<", option_label(option))
+        } else {
+            option_label(option).to_string()
+        };
+        let color = option_color(option);
+        let dims = measure_text(&text, None, option_size as u16, 1.0);
+        let x = (screen_width - dims.width) / 2.0;
+        let y = option_y(index);
+        if is_selected {
+            draw_rectangle(
+                x - 10.0,
+                y - 25.0,
+                dims.width + 20.0,
+                option_size + 10.0,
+                Color::new(color.r, color.g, color.b, 0.1),
+            );
+        }
+
+        draw_text(&text, x, y, option_size, if is_selected { color } else { GRAY });
     }
 
-    draw_text(start_text, start_x, start_y, option_size, start_color);
-
-    let sound_x = (screen_width - sound_dims.width) / 2.0;
-    let sound_y = screen_height * 0.7;
-    if matches!(selection, MenuSelection::SoundSettings) {
-        draw_rectangle(
-            sound_x - 10.0,
-            sound_y - 25.0,
-            sound_dims.width + 20.0,
-            option_size + 10.0,
-            Color::new(0.0, 0.0, 1.0, 0.1),
+    if let Some(error) = save_error {
+        let message = format!("⚠️ {}", error);
+        let message_size = 16.0;
+        let message_dims = measure_text(&message, None, message_size as u16, 1.0);
+        draw_text_with_emoji(
+            &message,
+            ((screen_width - message_dims.width) / 2.0).max(10.0),
+            screen_height * 0.3,
+            message_size,
+            RED,
+            emoji_font,
         );
     }
+}
 
-    draw_text(sound_text, sound_x, sound_y, option_size, sound_color);
+fn option_label(option: MenuSelection) -> &'static str {
+    match option {
+        MenuSelection::Continue => "Continue",
+        MenuSelection::Start => "Start",
+        MenuSelection::Run => "Run Mode",
+        MenuSelection::SoundSettings => "Sound Settings",
+        MenuSelection::Profiles => "Change Profile",
+        MenuSelection::Quit => "Quit",
+    }
+}
 
-    let quit_x = (screen_width - quit_dims.width) / 2.0;
-    let quit_y = screen_height * 0.8;
-    if matches!(selection, MenuSelection::Quit) {
-        draw_rectangle(
-            quit_x - 10.0,
-            quit_y - 25.0,
-            quit_dims.width + 20.0,
-            option_size + 10.0,
-            Color::new(1.0, 0.0, 0.0, 0.1),
-        );
+fn option_color(option: MenuSelection) -> Color {
+    match option {
+        MenuSelection::Continue => GOLD,
+        MenuSelection::Start => LIME,
+        MenuSelection::Run => ORANGE,
+        MenuSelection::SoundSettings => BLUE,
+        MenuSelection::Profiles => SKYBLUE,
+        MenuSelection::Quit => RED,
     }
+}
 
-    draw_text(quit_text, quit_x, quit_y, option_size, quit_color);
+fn option_y(index: usize) -> f32 {
+    screen_height() * (0.58 + index as f32 * 0.07)
 }
 
 /// Detecta qual opção do menu foi clicada
 pub fn get_clicked_menu_option(mouse_x: f32, mouse_y: f32) -> Option<MenuSelection> {
     let screen_width = screen_width();
-    let screen_height = screen_height();
-
-    let start_y = screen_height * 0.6;
-    let start_text = ">> Start <<";
     let option_size = 30.0;
-    let start_dims = measure_text(start_text, None, option_size as u16, 1.0);
-    let start_x = (screen_width - start_dims.width) / 2.0;
-
-    if mouse_x >= start_x - 20.0
-        && mouse_x <= start_x + start_dims.width + 20.0
-        && mouse_y >= start_y - 20.0
-        && mouse_y <= start_y + option_size + 10.0
-    {
-        return Some(MenuSelection::Start);
-    }
-
-    let sound_y = screen_height * 0.7;
-    let sound_text = ">> Sound Settings <<";
-    let sound_dims = measure_text(sound_text, None, option_size as u16, 1.0);
-    let sound_x = (screen_width - sound_dims.width) / 2.0;
-
-    if mouse_x >= sound_x - 20.0
-        && mouse_x <= sound_x + sound_dims.width + 20.0
-        && mouse_y >= sound_y - 20.0
-        && mouse_y <= sound_y + option_size + 10.0
-    {
-        return Some(MenuSelection::SoundSettings);
-    }
-
-    let quit_y = screen_height * 0.8;
-    let quit_text = ">> Quit <<";
-    let quit_dims = measure_text(quit_text, None, option_size as u16, 1.0);
-    let quit_x = (screen_width - quit_dims.width) / 2.0;
-
-    if mouse_x >= quit_x - 20.0
-        && mouse_x <= quit_x + quit_dims.width + 20.0
-        && mouse_y >= quit_y - 20.0
-        && mouse_y <= quit_y + option_size + 10.0
-    {
-        return Some(MenuSelection::Quit);
-    }
 
-    None
+    MenuSelection::ALL.into_iter().enumerate().find_map(|(index, option)| {
+        let text = format!(">> {} <<", option_label(option));
+        let dims = measure_text(&text, None, option_size as u16, 1.0);
+        let x = (screen_width - dims.width) / 2.0;
+        let y = option_y(index);
+        let clicked = mouse_x >= x - 20.0
+            && mouse_x <= x + dims.width + 20.0
+            && mouse_y >= y - option_size + 5.0
+            && mouse_y <= y + 12.0;
+        clicked.then_some(option)
+    })
 }
 
 /// Detecta se o campo de nome foi clicado
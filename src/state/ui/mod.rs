@@ -3,4 +3,11 @@ pub mod battle;
 pub mod game_over;
 pub mod components;
 pub mod enemy_selection;
-pub mod sound_settings;
\ No newline at end of file
+pub mod sound_settings;
+pub mod replay;
+pub mod deck_editor;
+pub mod card_reward;
+pub mod run_map;
+pub mod shop;
+pub mod profile_select;
+pub mod resume_battle;
@@ -0,0 +1,93 @@
+use macroquad::prelude::*;
+use crate::state::profile_state::{ProfileSlot, ProfileState};
+use crate::state::ui::components::draw_text_with_emoji;
+
+const SLOT_WIDTH: f32 = 520.0;
+const SLOT_HEIGHT: f32 = 70.0;
+const SLOT_SPACING: f32 = 14.0;
+
+fn slot_rect(index: usize) -> Rect {
+    Rect::new(
+        (screen_width() - SLOT_WIDTH) / 2.0,
+        screen_height() * 0.2 + index as f32 * (SLOT_HEIGHT + SLOT_SPACING),
+        SLOT_WIDTH,
+        SLOT_HEIGHT,
+    )
+}
+
+pub fn draw_profile_select(profiles: &ProfileState, emoji_font: Option<&Font>) {
+    let screen_width = screen_width();
+    let screen_height = screen_height();
+
+    let title = "👤 CHOOSE A PROFILE 👤";
+    let title_size = 36.0;
+    let title_dims = measure_text(title, None, title_size as u16, 1.0);
+    draw_text_with_emoji(title, (screen_width - title_dims.width) / 2.0, screen_height * 0.12, title_size, GOLD, emoji_font);
+
+    let (mouse_x, mouse_y) = mouse_position();
+    for (i, slot) in profiles.slots.iter().enumerate() {
+        let rect = slot_rect(i);
+        let is_selected = i == profiles.selected_index;
+        let is_hovered = rect.contains(vec2(mouse_x, mouse_y));
+        let bg_color = if is_selected {
+            Color::new(0.3, 0.3, 0.6, 0.9)
+        } else if is_hovered {
+            Color::new(0.25, 0.25, 0.5, 0.9)
+        } else {
+            Color::new(0.2, 0.2, 0.4, 0.9)
+        };
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, bg_color);
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, if is_selected { 3.0 } else { 1.0 }, if is_selected { YELLOW } else { GRAY });
+
+        draw_text(&format!("Slot {}", i + 1), rect.x + 14.0, rect.y + 26.0, 18.0, LIGHTGRAY);
+        match (slot, &profiles.new_name) {
+            (ProfileSlot::Empty, Some(name)) if is_selected => {
+                draw_text(&format!("Name: {}|", name), rect.x + 100.0, rect.y + 30.0, 24.0, WHITE);
+                draw_text("Type a name and press Enter to create", rect.x + 100.0, rect.y + 54.0, 16.0, LIGHTGRAY);
+            }
+            (ProfileSlot::Empty, _) => {
+                draw_text("Empty slot - Enter to create a profile", rect.x + 100.0, rect.y + 42.0, 20.0, GRAY);
+            }
+            (ProfileSlot::Corrupt, _) => {
+                draw_text("Unreadable save", rect.x + 100.0, rect.y + 30.0, 24.0, RED);
+                draw_text("Enter: keep it as a backup and start over  |  Delete: erase it", rect.x + 100.0, rect.y + 54.0, 16.0, ORANGE);
+            }
+            (ProfileSlot::Profile(summary), _) => {
+                draw_text(&summary.player_name, rect.x + 100.0, rect.y + 30.0, 24.0, WHITE);
+                let details = format!("Level {}  |  Enemies defeated: {}", summary.level, summary.enemies_defeated);
+                if summary.warning.is_some() {
+                    draw_text(&format!("{}  |  from a backup", details), rect.x + 100.0, rect.y + 54.0, 16.0, ORANGE);
+                } else {
+                    draw_text(&details, rect.x + 100.0, rect.y + 54.0, 16.0, SKYBLUE);
+                }
+            }
+        }
+    }
+
+    if let Some(message) = &profiles.message {
+        let message_dims = measure_text(message, None, 18, 1.0);
+        draw_text(
+            message,
+            ((screen_width - message_dims.width) / 2.0).max(10.0),
+            screen_height * 0.88,
+            18.0,
+            ORANGE,
+        );
+    }
+
+    let controls = "W/S: Select | Enter/Click: Play or create | C: Copy to empty slot | Delete: Erase | ESC: Quit";
+    let controls_size = 16.0;
+    let controls_dims = measure_text(controls, None, controls_size as u16, 1.0);
+    draw_text(
+        controls,
+        (screen_width - controls_dims.width) / 2.0,
+        screen_height * 0.95,
+        controls_size,
+        LIGHTGRAY,
+    );
+}
+
+/// Detecta qual slot foi clicado
+pub fn get_clicked_profile_slot(mouse_x: f32, mouse_y: f32, slot_count: usize) -> Option<usize> {
+    (0..slot_count).find(|index| slot_rect(*index).contains(vec2(mouse_x, mouse_y)))
+}
@@ -0,0 +1,42 @@
+use macroquad::prelude::*;
+use crate::state::replay_state::ReplayState;
+use crate::deck::CardTextureManager;
+use super::battle::draw_battle;
+
+pub fn draw_replay(replay: &ReplayState, card_textures: &CardTextureManager, emoji_font: Option<&Font>) {
+    draw_battle(&replay.battle, card_textures, emoji_font);
+
+    let screen_width = screen_width();
+    let screen_height = screen_height();
+    let bar_height = 36.0;
+    let bar_y = screen_height - bar_height;
+
+    draw_rectangle(0.0, bar_y, screen_width, bar_height, Color::new(0.0, 0.0, 0.0, 0.8));
+
+    let state_text = if replay.is_finished() {
+        "REPLAY ENDED".to_string()
+    } else if replay.paused {
+        "REPLAY PAUSED".to_string()
+    } else {
+        format!("REPLAY {:.0}x", replay.speed())
+    };
+    let progress_text = format!(
+        "{}  |  Step {}/{}  |  Seed {}",
+        state_text,
+        replay.next_action,
+        replay.replay.actions.len(),
+        replay.replay.seed
+    );
+    draw_text(&progress_text, 20.0, bar_y + 24.0, 18.0, GOLD);
+
+    let controls = "Space: Pause | F: Speed | Right: Step | ESC: Back";
+    let controls_size = 16.0;
+    let controls_dims = measure_text(controls, None, controls_size as u16, 1.0);
+    draw_text(
+        controls,
+        screen_width - controls_dims.width - 20.0,
+        bar_y + 24.0,
+        controls_size,
+        LIGHTGRAY,
+    );
+}
@@ -0,0 +1,49 @@
+use macroquad::prelude::*;
+use crate::state::game_state::SuspendedBattle;
+use crate::state::ui::components::draw_text_with_emoji;
+
+pub fn draw_resume_battle(suspended: &SuspendedBattle, emoji_font: Option<&Font>) {
+    let screen_width = screen_width();
+    let screen_height = screen_height();
+
+    draw_rectangle(0.0, 0.0, screen_width, screen_height, Color::new(0.0, 0.0, 0.0, 0.8));
+
+    let panel_width = 520.0;
+    let panel_height = 260.0;
+    let panel_x = (screen_width - panel_width) / 2.0;
+    let panel_y = (screen_height - panel_height) / 2.0;
+    draw_rectangle(panel_x, panel_y, panel_width, panel_height, Color::new(0.1, 0.1, 0.2, 0.95));
+    draw_rectangle_lines(panel_x, panel_y, panel_width, panel_height, 3.0, GOLD);
+
+    let title = "⚔️ BATTLE IN PROGRESS ⚔️";
+    let title_size = 30.0;
+    let title_dims = measure_text(title, None, title_size as u16, 1.0);
+    draw_text_with_emoji(title, panel_x + (panel_width - title_dims.width) / 2.0, panel_y + 50.0, title_size, GOLD, emoji_font);
+
+    let engine = &suspended.battle.engine;
+    let enemy_names: Vec<&str> = engine
+        .living_enemies()
+        .map(|index| engine.enemies[index].name.as_str())
+        .collect();
+    let lines = [
+        (format!("You left a fight against {}", enemy_names.join(", ")), WHITE),
+        (
+            format!(
+                "Turn {}/{}  |  HP {}/{}",
+                engine.turn.turn + 1,
+                engine.turn.max_turn,
+                engine.player.health,
+                engine.player.max_health
+            ),
+            LIGHTGRAY,
+        ),
+    ];
+    for (i, (line, color)) in lines.iter().enumerate() {
+        let line_dims = measure_text(line, None, 20, 1.0);
+        draw_text(line, panel_x + (panel_width - line_dims.width) / 2.0, panel_y + 110.0 + i as f32 * 32.0, 20.0, *color);
+    }
+
+    let controls = "Enter: Resume from this turn | ESC: Abandon the battle";
+    let controls_dims = measure_text(controls, None, 18, 1.0);
+    draw_text(controls, panel_x + (panel_width - controls_dims.width) / 2.0, panel_y + panel_height - 30.0, 18.0, LIME);
+}
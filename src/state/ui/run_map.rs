@@ -0,0 +1,135 @@
+use macroquad::prelude::*;
+use crate::run::{NodeKind, RunState};
+use crate::state::run_map_state::RunMapState;
+use crate::state::ui::components::{draw_health_bar, draw_text_with_emoji};
+
+const NODE_RADIUS: f32 = 20.0;
+
+/// Centro da parada na tela; o primeiro andar fica embaixo e o chefe no topo
+fn node_position(run: &RunState, node: usize) -> Vec2 {
+    let map_node = &run.map.nodes[node];
+    let left = 120.0;
+    let right = screen_width() - 120.0;
+    let bottom = screen_height() * 0.82;
+    let top = screen_height() * 0.2;
+    let row_height = (bottom - top) / (run.map.rows - 1).max(1) as f32;
+    vec2(left + map_node.x * (right - left), bottom - map_node.row as f32 * row_height)
+}
+
+pub fn get_clicked_run_node(run: &RunState, mouse_x: f32, mouse_y: f32) -> Option<usize> {
+    let mouse = vec2(mouse_x, mouse_y);
+    run.available()
+        .into_iter()
+        .find(|node| node_position(run, *node).distance(mouse) <= NODE_RADIUS)
+}
+
+fn node_color(kind: NodeKind) -> Color {
+    match kind {
+        NodeKind::Battle => Color::new(0.45, 0.2, 0.2, 1.0),
+        NodeKind::Elite => Color::new(0.55, 0.1, 0.35, 1.0),
+        NodeKind::Rest => Color::new(0.55, 0.35, 0.1, 1.0),
+        NodeKind::Shop => Color::new(0.5, 0.45, 0.1, 1.0),
+        NodeKind::Event => Color::new(0.2, 0.3, 0.5, 1.0),
+        NodeKind::Boss => Color::new(0.6, 0.05, 0.05, 1.0),
+    }
+}
+
+pub fn draw_run_map(run: &RunState, map_state: &RunMapState, gold: u32, emoji_font: Option<&Font>) {
+    let screen_width = screen_width();
+    let screen_height = screen_height();
+
+    draw_rectangle(0.0, 0.0, screen_width, screen_height, Color::new(0.0, 0.0, 0.0, 0.6));
+
+    let title = "🗺️ RUN MAP 🗺️";
+    let title_size = 34.0;
+    let title_dims = measure_text(title, None, title_size as u16, 1.0);
+    draw_text_with_emoji(title, (screen_width - title_dims.width) / 2.0, screen_height * 0.07, title_size, GOLD, emoji_font);
+
+    let info = format!(
+        "Floor {}/{}  |  HP {}/{}  |  Deck {} cards  |  Gold {}  |  Seed {}",
+        (run.floor() + 1).min(run.map.rows),
+        run.map.rows,
+        run.health,
+        run.max_health,
+        run.deck.deck_size(),
+        gold,
+        run.seed
+    );
+    let info_dims = measure_text(&info, None, 18, 1.0);
+    draw_text(&info, (screen_width - info_dims.width) / 2.0, screen_height * 0.11, 18.0, WHITE);
+    draw_health_bar(
+        (screen_width - 300.0) / 2.0,
+        screen_height * 0.125,
+        300.0,
+        10.0,
+        run.health,
+        run.max_health,
+        GREEN,
+        Color::new(0.0, 0.3, 0.0, 1.0),
+    );
+
+    // Caminhos; os já percorridos em dourado
+    for (from, map_node) in run.map.nodes.iter().enumerate() {
+        for &to in &map_node.next {
+            let walked = run.path.windows(2).any(|step| step[0] == from && step[1] == to);
+            let (color, thickness) = if walked { (GOLD, 4.0) } else { (Color::new(0.5, 0.5, 0.5, 0.6), 2.0) };
+            let start = node_position(run, from);
+            let end = node_position(run, to);
+            draw_line(start.x, start.y, end.x, end.y, thickness, color);
+        }
+    }
+
+    let available = run.available();
+    let selected = available.get(map_state.selected_index).copied();
+    let pulse = ((get_time() * 4.0).sin() * 0.5 + 0.5) as f32;
+    let mouse = Vec2::from(mouse_position());
+    let mut hovered = None;
+    for (node, map_node) in run.map.nodes.iter().enumerate() {
+        let position = node_position(run, node);
+        let visited = run.path.contains(&node);
+        let reachable = available.contains(&node);
+        let mut fill = node_color(map_node.kind);
+        if !visited && !reachable {
+            fill = Color::new(fill.r * 0.5, fill.g * 0.5, fill.b * 0.5, 1.0);
+        }
+        draw_circle(position.x, position.y, NODE_RADIUS, fill);
+
+        if visited {
+            draw_circle_lines(position.x, position.y, NODE_RADIUS, 3.0, GOLD);
+        } else if Some(node) == selected {
+            draw_circle_lines(position.x, position.y, NODE_RADIUS + 4.0, 4.0, YELLOW);
+        } else if reachable {
+            draw_circle_lines(position.x, position.y, NODE_RADIUS + 2.0, 2.0, Color::new(1.0, 1.0, 1.0, 0.4 + pulse * 0.6));
+        } else {
+            draw_circle_lines(position.x, position.y, NODE_RADIUS, 1.0, DARKGRAY);
+        }
+        draw_text_with_emoji(map_node.kind.icon(), position.x - 10.0, position.y + 7.0, 20.0, WHITE, emoji_font);
+
+        if position.distance(mouse) <= NODE_RADIUS {
+            hovered = Some(node);
+        }
+    }
+
+    if let Some(node) = hovered.or(selected) {
+        let map_node = &run.map.nodes[node];
+        let label = format!("{} {}", map_node.kind.icon(), map_node.kind.label());
+        let position = node_position(run, node);
+        draw_text_with_emoji(&label, position.x + NODE_RADIUS + 8.0, position.y + 6.0, 18.0, YELLOW, emoji_font);
+    }
+
+    if let Some(message) = &map_state.message {
+        let message_dims = measure_text(message, None, 20, 1.0);
+        draw_text_with_emoji(message, (screen_width - message_dims.width) / 2.0, screen_height * 0.9, 20.0, SKYBLUE, emoji_font);
+    }
+
+    let controls = "Left/Right: Choose | Enter/Click: Go | ESC: Menu | Shift+X: Abandon run";
+    let controls_size = 16.0;
+    let controls_dims = measure_text(controls, None, controls_size as u16, 1.0);
+    draw_text(
+        controls,
+        (screen_width - controls_dims.width) / 2.0,
+        screen_height - 15.0,
+        controls_size,
+        LIGHTGRAY,
+    );
+}
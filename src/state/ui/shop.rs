@@ -0,0 +1,177 @@
+use macroquad::prelude::*;
+use crate::card_catalog::CardCatalog;
+use crate::collection::CardCollection;
+use crate::config::config::*;
+use crate::deck::CardTextureManager;
+use crate::state::shop_state::ShopState;
+use crate::state::ui::components::draw_text_with_emoji;
+
+const ROW_HEIGHT: f32 = 26.0;
+const ROW_WIDTH: f32 = 380.0;
+const BUTTON_WIDTH: f32 = 70.0;
+const OFFER_SCALE: f32 = 0.7;
+const OFFER_COLUMNS: usize = 3;
+const OFFER_SPACING: f32 = 24.0;
+
+/// Elemento da loja sob o mouse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShopClick {
+    Offer(usize),
+    Card(usize),
+    Remove(usize),
+    Upgrade(usize),
+}
+
+fn rows_origin() -> (f32, f32) {
+    (screen_width() * 0.05, screen_height() * 0.18)
+}
+
+fn row_rect(index: usize) -> Rect {
+    let (row_x, start_y) = rows_origin();
+    Rect::new(row_x, start_y + index as f32 * ROW_HEIGHT, ROW_WIDTH, ROW_HEIGHT - 3.0)
+}
+
+fn button_rects(index: usize, row: Rect) -> [(ShopClick, Rect); 2] {
+    let upgrade_x = row.x + row.w - BUTTON_WIDTH - 4.0;
+    let remove_x = upgrade_x - BUTTON_WIDTH - 6.0;
+    [
+        (ShopClick::Remove(index), Rect::new(remove_x, row.y + 2.0, BUTTON_WIDTH, row.h - 4.0)),
+        (ShopClick::Upgrade(index), Rect::new(upgrade_x, row.y + 2.0, BUTTON_WIDTH, row.h - 4.0)),
+    ]
+}
+
+fn offer_rect(index: usize) -> Rect {
+    let width = CARD_WIDTH * OFFER_SCALE;
+    let height = CARD_HEIGHT * OFFER_SCALE;
+    let column = index % OFFER_COLUMNS;
+    let row = index / OFFER_COLUMNS;
+    Rect::new(
+        screen_width() * 0.55 + column as f32 * (width + OFFER_SPACING),
+        screen_height() * 0.18 + row as f32 * (height + 50.0),
+        width,
+        height,
+    )
+}
+
+pub fn draw_shop(
+    shop: &ShopState,
+    collection: &CardCollection,
+    catalog: &CardCatalog,
+    card_textures: &CardTextureManager,
+    gold: u32,
+    emoji_font: Option<&Font>,
+) {
+    let screen_width = screen_width();
+    let screen_height = screen_height();
+
+    draw_rectangle(0.0, 0.0, screen_width, screen_height, Color::new(0.0, 0.0, 0.0, 0.7));
+
+    let title = "💰 SHOP 💰";
+    let title_size = 36.0;
+    let title_dims = measure_text(title, None, title_size as u16, 1.0);
+    draw_text_with_emoji(title, (screen_width - title_dims.width) / 2.0, screen_height * 0.08, title_size, GOLD, emoji_font);
+
+    let info = format!(
+        "Gold: {}  |  Remove: {}  |  Upgrade: {}  |  Deck: {} cards",
+        gold,
+        SHOP_REMOVE_COST,
+        SHOP_UPGRADE_COST,
+        collection.deck_size()
+    );
+    let info_dims = measure_text(&info, None, 20, 1.0);
+    draw_text(&info, (screen_width - info_dims.width) / 2.0, screen_height * 0.13, 20.0, YELLOW);
+
+    let (mouse_x, mouse_y) = mouse_position();
+    let mouse = vec2(mouse_x, mouse_y);
+
+    for (i, id) in shop.card_ids.iter().enumerate() {
+        let Some(definition) = catalog.get(id) else {
+            continue;
+        };
+        let row = row_rect(i);
+        let is_selected = i == shop.selected_index;
+        let bg_color = if is_selected {
+            Color::new(0.3, 0.3, 0.6, 0.9)
+        } else {
+            Color::new(0.2, 0.2, 0.4, 0.9)
+        };
+        draw_rectangle(row.x, row.y, row.w, row.h, bg_color);
+        draw_rectangle_lines(row.x, row.y, row.w, row.h, if is_selected { 2.0 } else { 1.0 }, if is_selected { YELLOW } else { GRAY });
+
+        let upgraded = collection.is_upgraded(id);
+        let name = if upgraded { format!("{}+", definition.name) } else { definition.name.clone() };
+        draw_text(&name, row.x + 8.0, row.y + 17.0, 18.0, if upgraded { LIME } else { WHITE });
+        let copies = format!("x{}", collection.owned_copies(id));
+        draw_text(&copies, row.x + 180.0, row.y + 17.0, 18.0, LIGHTGRAY);
+
+        for (button, rect) in button_rects(i, row) {
+            let hovered = rect.contains(mouse);
+            let (label, color) = match button {
+                ShopClick::Remove(_) => ("Remove", if hovered { RED } else { MAROON }),
+                _ if upgraded => ("Done", DARKGRAY),
+                _ => ("Upgrade", if hovered { LIME } else { DARKGREEN }),
+            };
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, color);
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, WHITE);
+            let label_dims = measure_text(label, None, 16, 1.0);
+            draw_text(label, rect.x + (rect.w - label_dims.width) / 2.0, rect.y + 15.0, 16.0, WHITE);
+        }
+    }
+
+    for (i, offer) in shop.offers.iter().enumerate() {
+        let rect = offer_rect(i);
+        let hovered = !offer.sold && rect.contains(mouse);
+        let lift = if hovered { 8.0 } else { 0.0 };
+        card_textures.draw_card(&offer.card, rect.x, rect.y - lift, rect.w, rect.h);
+        if offer.sold {
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, Color::new(0.0, 0.0, 0.0, 0.6));
+        }
+
+        let (label, color) = if offer.sold {
+            ("SOLD".to_string(), GRAY)
+        } else {
+            (format!("{}. {} gold", i + 1, offer.price), if gold >= offer.price { GOLD } else { RED })
+        };
+        let label_dims = measure_text(&label, None, 16, 1.0);
+        draw_text(&label, rect.x + (rect.w - label_dims.width) / 2.0, rect.y + rect.h + 20.0, 16.0, color);
+    }
+
+    if let Some(message) = &shop.message {
+        let message_dims = measure_text(message, None, 18, 1.0);
+        draw_text(message, (screen_width - message_dims.width) / 2.0, screen_height * 0.90, 18.0, ORANGE);
+    }
+
+    let controls = "1-6/Click: Buy | W/S: Select | R: Remove | U: Upgrade | ESC: Leave";
+    let controls_size = 16.0;
+    let controls_dims = measure_text(controls, None, controls_size as u16, 1.0);
+    draw_text(
+        controls,
+        (screen_width - controls_dims.width) / 2.0,
+        screen_height * 0.95,
+        controls_size,
+        LIGHTGRAY,
+    );
+}
+
+/// Devolve a oferta, a linha ou o botão da linha sob o mouse, se houver
+pub fn get_clicked_shop_element(shop: &ShopState, mouse_x: f32, mouse_y: f32) -> Option<ShopClick> {
+    let mouse = vec2(mouse_x, mouse_y);
+
+    if let Some(index) = (0..shop.offers.len()).find(|index| offer_rect(*index).contains(mouse)) {
+        return Some(ShopClick::Offer(index));
+    }
+
+    for index in 0..shop.card_ids.len() {
+        let row = row_rect(index);
+        for (button, rect) in button_rects(index, row) {
+            if rect.contains(mouse) {
+                return Some(button);
+            }
+        }
+        if row.contains(mouse) {
+            return Some(ShopClick::Card(index));
+        }
+    }
+
+    None
+}
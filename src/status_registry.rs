@@ -0,0 +1,121 @@
+use crate::effects::{ActiveStatus, Stacking, StatusEffect, StatusTrigger};
+use crate::error::{GameError, GameResult};
+use macroquad::file::load_string;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+fn default_multiplier() -> f32 {
+    1.0
+}
+
+/// Definição de um efeito de status no arquivo de dados
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusDefinition {
+    pub effect: StatusEffect,
+    pub name: String,
+    pub icon: String,
+    pub description: String,
+    pub stacking: Stacking,
+    pub trigger: StatusTrigger,
+    /// Efeitos benéficos vão para quem usa a carta; os outros, para o oponente
+    #[serde(default)]
+    pub beneficial: bool,
+    /// Dano e cura por disparo, multiplicados pelas camadas
+    #[serde(default)]
+    pub tick_damage: u32,
+    #[serde(default)]
+    pub tick_heal: u32,
+    /// Multiplicador do dano causado pelo afetado
+    #[serde(default = "default_multiplier")]
+    pub damage_dealt_multiplier: f32,
+    /// Multiplicador do dano recebido pelo afetado
+    #[serde(default = "default_multiplier")]
+    pub damage_taken_multiplier: f32,
+    /// O afetado perde o turno enquanto o efeito durar
+    #[serde(default)]
+    pub skips_turn: bool,
+    /// Dano devolvido a quem golpeia o afetado, por camada
+    #[serde(default)]
+    pub thorns: u32,
+}
+
+impl StatusDefinition {
+    /// Dano do próximo disparo, considerando as camadas acumuladas
+    pub fn tick_damage_for(&self, active: &ActiveStatus) -> u32 {
+        self.tick_damage * active.stacks
+    }
+
+    pub fn tick_heal_for(&self, active: &ActiveStatus) -> u32 {
+        self.tick_heal * active.stacks
+    }
+
+    pub fn thorns_for(&self, active: &ActiveStatus) -> u32 {
+        self.thorns * active.stacks
+    }
+}
+
+/// Todos os efeitos de status do jogo, carregados de `assets/data/status_effects.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusRegistry {
+    pub effects: Vec<StatusDefinition>,
+}
+
+impl Default for StatusRegistry {
+    fn default() -> Self {
+        Self::embedded()
+    }
+}
+
+impl StatusRegistry {
+    pub const PATH: &'static str = "assets/data/status_effects.json";
+
+    pub fn from_json(json: &str) -> GameResult<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| GameError::AssetLoadError(format!("{}: {}", Self::PATH, e)))
+    }
+
+    /// Cópia dos efeitos embutida no executável, usada se o arquivo não puder ser lido
+    pub fn embedded() -> Self {
+        Self::from_json(include_str!("../assets/data/status_effects.json"))
+            .expect("embedded status effects must be valid")
+    }
+
+    pub async fn load() -> Self {
+        match load_string(Self::PATH).await {
+            Ok(json) => Self::from_json(&json).unwrap_or_else(|e| {
+                eprintln!("⚠️ {}, using built-in status effects", e);
+                Self::embedded()
+            }),
+            Err(_) => Self::embedded(),
+        }
+    }
+
+    pub fn get(&self, effect: StatusEffect) -> Option<&StatusDefinition> {
+        self.effects.iter().find(|definition| definition.effect == effect)
+    }
+
+    fn active<'a>(
+        &'a self,
+        effects: &'a BTreeMap<StatusEffect, ActiveStatus>,
+    ) -> impl Iterator<Item = (&'a StatusDefinition, &'a ActiveStatus)> {
+        effects
+            .iter()
+            .filter_map(|(effect, active)| self.get(*effect).map(|definition| (definition, active)))
+    }
+
+    pub fn damage_dealt_multiplier(&self, effects: &BTreeMap<StatusEffect, ActiveStatus>) -> f32 {
+        self.active(effects).map(|(definition, _)| definition.damage_dealt_multiplier).product()
+    }
+
+    pub fn damage_taken_multiplier(&self, effects: &BTreeMap<StatusEffect, ActiveStatus>) -> f32 {
+        self.active(effects).map(|(definition, _)| definition.damage_taken_multiplier).product()
+    }
+
+    pub fn skips_turn(&self, effects: &BTreeMap<StatusEffect, ActiveStatus>) -> bool {
+        self.active(effects).any(|(definition, _)| definition.skips_turn)
+    }
+
+    pub fn thorns(&self, effects: &BTreeMap<StatusEffect, ActiveStatus>) -> u32 {
+        self.active(effects).map(|(definition, active)| definition.thorns_for(active)).sum()
+    }
+}